@@ -19,9 +19,33 @@ pub(crate) fn pattern_for(
     to: usize,
     prefix: &str,
     suffix: &str,
+) -> Result<String, std::fmt::Error> {
+    pattern_for_range(1, to, prefix, suffix)
+}
+
+pub(crate) fn pattern_for_range(
+    from: usize,
+    to: usize,
+    prefix: &str,
+    suffix: &str,
 ) -> Result<String, std::fmt::Error> {
     let mut args = String::new();
-    for i in 1..=to {
+    for i in from..=to {
+        if !args.is_empty() {
+            args.push(' ');
+        }
+        write!(args, "{}{}{},", prefix, i, suffix)?;
+    }
+    Ok(args)
+}
+
+pub(crate) fn pattern_rev(
+    to: usize,
+    prefix: &str,
+    suffix: &str,
+) -> Result<String, std::fmt::Error> {
+    let mut args = String::new();
+    for i in (1..=to).rev() {
         if !args.is_empty() {
             args.push(' ');
         }
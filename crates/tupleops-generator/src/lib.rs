@@ -15,40 +15,74 @@
 
 mod common;
 mod one_arg;
+mod two_args;
 
 use std::fmt::Error;
 
 use common::gen_range;
 
 macro_rules! implement {
-    () => {};
+    ( $module:ident => ) => {};
 
-    ( $name:ident ) => {
+    ( $module:ident => $name:ident $($names:ident)* ) => {
         pub fn $name(dest: &mut String, from: usize, to: usize) -> Result<(), Error> {
-            gen_range(dest, from, to, one_arg::$name)
+            gen_range(dest, from, to, $module::$name)
         }
-    };
 
-    ( $name:ident $($names:ident)+ ) => {
-        implement! { $name }
-        implement! { $($names)+ }
+        implement! { $module => $($names)* }
     };
 }
 
 implement! {
+    one_arg =>
+    tuple_all_ok_error
     tuple_all_ok
+    tuple_all_some_error
     tuple_all_some
+    tuple_any_some
     tuple_append
     tuple_apply
+    tuple_array
+    tuple_as_dyn
+    tuple_collect_errors
+    tuple_collect_option
+    tuple_collect_result
     tuple_concat_many
     tuple_concat
+    tuple_first_ok
+    tuple_flatten
+    tuple_fold
+    tuple_for_each
+    tuple_get
+    tuple_insert_at
+    tuple_iter
+    tuple_join
     tuple_length
+    tuple_map_homogeneous
+    tuple_map_mut
+    tuple_map_ref
     tuple_map
     tuple_option
     tuple_prepend
     tuple_ref_mut
     tuple_ref
+    tuple_remove_at
+    tuple_replace_at
+    tuple_result
+    tuple_reverse
+    tuple_rotate
+    tuple_split_at
+    tuple_transpose
+    tuple_try_all
+    tuple_try_map
     tuple_tuple
     tuple_unappend
     tuple_unprepend
+    tuple_unzip
+}
+
+implement! {
+    two_args =>
+    tuple_zip_with
+    tuple_zip
 }
@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use crate::common::{pattern_for, pattern_for2};
+use crate::common::{pattern_for, pattern_for2, pattern_for_range, pattern_rev};
 
 pub(crate) fn tuple_all_ok(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
     write!(
@@ -25,6 +25,58 @@ impl<{generics}> TupleAllOk<({results})> for ({results}) {{
     )
 }
 
+pub(crate) fn tuple_all_ok_error(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut bounds = String::new();
+    let mut sources = String::new();
+    for i in 1..=to {
+        write!(
+            bounds,
+            "
+    E{0}: Error + 'static,",
+            i,
+        )?;
+        write!(
+            sources,
+            "
+        if let Err(err) = i{0} {{
+            return Some(err);
+        }}",
+            i,
+        )?;
+    }
+    write!(
+        dest,
+        "\
+impl<{generics}> TupleAllOkError<({results})> for ({results}) {{
+    type Failed = [bool; {to}];
+
+    #[inline(always)]
+    fn failed(tpl: &({results})) -> Self::Failed {{
+        let ({vals}) = tpl;
+        [{failed}]
+    }}
+}}
+
+#[cfg(feature = \"core-error\")]
+impl<{generics}> TupleAllOkSource<({results})> for ({results})
+where{bounds}
+{{
+    #[inline(always)]
+    fn source(tpl: &({results})) -> Option<&(dyn Error + 'static)> {{
+        let ({vals}) = tpl;{sources}
+        None
+    }}
+}}",
+        generics = pattern_for2(to, "I", ", E", "")?,
+        results = pattern_for2(to, "Result<I", ", E", ">")?,
+        vals = pattern_for(to, "i", "")?,
+        failed = pattern_for(to, "i", ".is_err()")?,
+        bounds = bounds,
+        sources = sources,
+        to = to,
+    )
+}
+
 pub(crate) fn tuple_all_some(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
     write!(
         dest,
@@ -47,6 +99,62 @@ impl<{args}> TupleAllSome<({options})> for ({options}) {{
     )
 }
 
+pub(crate) fn tuple_all_some_error(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<{args}> TupleAllSomeError<({options})> for ({options}) {{
+    type Failed = [bool; {to}];
+
+    #[inline(always)]
+    fn failed(tpl: &({options})) -> Self::Failed {{
+        let ({vals}) = tpl;
+        [{failed}]
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        options = pattern_for(to, "Option<I", ">")?,
+        vals = pattern_for(to, "i", "")?,
+        failed = pattern_for(to, "i", ".is_none()")?,
+        to = to,
+    )
+}
+
+pub(crate) fn tuple_any_some(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut any = String::new();
+    let mut count = String::new();
+    for i in 1..=to {
+        if i > 1 {
+            any.push_str(" || ");
+            count.push_str(" + ");
+        }
+        write!(any, "(match i{} {{ Some(_) => true, None => false }})", i)?;
+        write!(count, "(match i{} {{ Some(_) => 1, None => 0 }})", i)?;
+    }
+    write!(
+        dest,
+        "\
+impl<{args}> TupleAnySome<({options})> for ({options}) {{
+    #[inline(always)]
+    fn any_some(tpl: &({options})) -> bool {{
+        let ({vals}) = tpl;
+        {any}
+    }}
+
+    #[inline(always)]
+    fn count_some(tpl: &({options})) -> usize {{
+        let ({vals}) = tpl;
+        {count}
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        options = pattern_for(to, "Option<I", ">")?,
+        vals = pattern_for(to, "i", "")?,
+        any = any,
+        count = count,
+    )
+}
+
 pub(crate) fn tuple_append(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
     write!(
         dest,
@@ -99,6 +207,143 @@ where
     )
 }
 
+pub(crate) fn tuple_array(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let elems = "T, ".repeat(to);
+    write!(
+        dest,
+        "\
+impl<T> TupleToArray<({elems})> for ({elems}) {{
+    type Type = [T; {to}];
+
+    #[inline(always)]
+    fn tuple_to_array(tpl: ({elems})) -> Self::Type {{
+        let ({vals}) = tpl;
+        [{vals}]
+    }}
+}}
+
+impl<T> ArrayToTuple<[T; {to}]> for [T; {to}] {{
+    type Type = ({elems});
+
+    #[inline(always)]
+    fn array_to_tuple(arr: [T; {to}]) -> Self::Type {{
+        let [{vals}] = arr;
+        ({vals})
+    }}
+}}",
+        elems = elems.trim_end(),
+        vals = pattern_for(to, "i", "")?,
+        to = to,
+    )
+}
+
+pub(crate) fn tuple_as_dyn(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut coerce_bounds = String::new();
+    for i in 1..=to {
+        if !coerce_bounds.is_empty() {
+            coerce_bounds.push_str(" + ");
+        }
+        write!(coerce_bounds, "CoerceDyn<I{}>", i)?;
+    }
+    write!(
+        dest,
+        "\
+impl<'a, Dyn, {args}> TupleAsDyn<'a, Dyn> for ({args})
+where
+    Dyn: ?Sized + {coerce_bounds} + 'a,
+{{
+    type Ref = ::core::array::IntoIter<&'a Dyn, {to}>;
+    type Mut = ::core::array::IntoIter<&'a mut Dyn, {to}>;
+
+    #[inline(always)]
+    fn iter_dyn(tpl: &'a Self) -> Self::Ref {{
+        let ({vals}) = tpl;
+        IntoIterator::into_iter([{ref_vals}])
+    }}
+
+    #[inline(always)]
+    fn iter_dyn_mut(tpl: &'a mut Self) -> Self::Mut {{
+        let ({vals}) = tpl;
+        IntoIterator::into_iter([{mut_vals}])
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        coerce_bounds = coerce_bounds,
+        ref_vals = pattern_for2(to, "<Dyn as CoerceDyn<I", ">>::coerce_ref(i", ")")?,
+        mut_vals = pattern_for2(to, "<Dyn as CoerceDyn<I", ">>::coerce_mut(i", ")")?,
+        to = to,
+    )
+}
+
+pub(crate) fn tuple_collect_errors(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<{generics}> TupleCollectErrors<({results})> for ({results}) {{
+    type Errors = ({errors});
+
+    #[inline(always)]
+    fn collect_errors(tpl: ({results})) -> Result<({args}), Self::Errors> {{
+        match tpl {{
+            ({oks}) => Ok(({vals})),
+            ({vals}) => Err(({errs})),
+        }}
+    }}
+}}",
+        generics = pattern_for2(to, "I", ", E", "")?,
+        results = pattern_for2(to, "Result<I", ", E", ">")?,
+        errors = pattern_for(to, "Option<E", ">")?,
+        args = pattern_for(to, "I", "")?,
+        oks = pattern_for(to, "Ok(i", ")")?,
+        vals = pattern_for(to, "i", "")?,
+        errs = pattern_for(to, "i", ".err()")?,
+    )
+}
+
+pub(crate) fn tuple_collect_option(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<{args}> TupleCollectOption<({options})> for ({options}) {{
+    type Type = ({args});
+
+    #[inline(always)]
+    fn collect_option(tpl: ({options})) -> Option<Self::Type> {{
+        match tpl {{
+            ({somes}) => Some(({vals})),
+            _ => None,
+        }}
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        options = pattern_for(to, "Option<I", ">")?,
+        somes = pattern_for(to, "Some(i", ")")?,
+        vals = pattern_for(to, "i", "")?,
+    )
+}
+
+pub(crate) fn tuple_collect_result(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<E, {args}> TupleCollectResult<({results})> for ({results}) {{
+    type Type = ({args});
+    type Error = E;
+
+    #[inline(always)]
+    fn collect_result(tpl: ({results})) -> Result<Self::Type, E> {{
+        let ({vals}) = tpl;
+        Ok(({tries}))
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        results = pattern_for(to, "Result<I", ", E>")?,
+        vals = pattern_for(to, "i", "")?,
+        tries = pattern_for(to, "i", "?")?,
+    )
+}
+
 pub(crate) fn tuple_concat_many(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
     write!(
         dest,
@@ -144,6 +389,365 @@ where
     )
 }
 
+pub(crate) fn tuple_first_ok(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut errs = String::new();
+    for i in 1..=to {
+        write!(
+            errs,
+            "
+        let e{0} = match r{0} {{
+            Ok(value) => return Ok(({1}, value)),
+            Err(err) => err,
+        }};",
+            i,
+            i - 1,
+        )?;
+    }
+    write!(
+        dest,
+        "\
+impl<T, {errors}> TupleFirstOk<({results})> for ({results}) {{
+    type Type = T;
+    type Errors = ({errors});
+
+    #[inline(always)]
+    fn first_ok(tpl: ({results})) -> Result<(usize, T), Self::Errors> {{
+        let ({rs}) = tpl;{errs}
+        Err(({es}))
+    }}
+}}
+
+",
+        errors = pattern_for(to, "E", "")?,
+        results = pattern_for(to, "Result<T, E", ">")?,
+        rs = pattern_for(to, "r", "")?,
+        errs = errs,
+        es = pattern_for(to, "e", "")?,
+    )?;
+
+    let results = pattern_for2(to, "Result<I", ", E", ">")?;
+    if to == 1 {
+        write!(
+            dest,
+            "\
+impl<I1, E1> TupleFirstOkEither<({results})> for ({results}) {{
+    type Type = I1;
+    type Errors = (E1,);
+
+    #[inline(always)]
+    fn first_ok_either(tpl: ({results})) -> Result<Self::Type, Self::Errors> {{
+        let (r1,) = tpl;
+        r1.map_err(|e1| (e1,))
+    }}
+}}",
+            results = results,
+        )
+    } else {
+        let mut rest_results = String::new();
+        for i in 2..=to {
+            if !rest_results.is_empty() {
+                rest_results.push(' ');
+            }
+            write!(rest_results, "Result<I{0}, E{0}>,", i)?;
+        }
+        write!(
+            dest,
+            "\
+impl<{generics}> TupleFirstOkEither<({results})> for ({results}) {{
+    type Type = Either<I1, <({rest_results}) as TupleFirstOkEither<({rest_results})>>::Type>;
+    type Errors = ({errors});
+
+    #[inline(always)]
+    fn first_ok_either(tpl: ({results})) -> Result<Self::Type, Self::Errors> {{
+        let ({rs}) = tpl;
+        let e1 = match r1 {{
+            Ok(value) => return Ok(Either::Left(value)),
+            Err(err) => err,
+        }};
+        match <({rest_results}) as TupleFirstOkEither<_>>::first_ok_either(({rest_rs})) {{
+            Ok(value) => Ok(Either::Right(value)),
+            Err(({rest_es})) => Err(({es})),
+        }}
+    }}
+}}",
+            generics = pattern_for2(to, "I", ", E", "")?,
+            results = results,
+            rest_results = rest_results,
+            errors = pattern_for(to, "E", "")?,
+            rs = pattern_for(to, "r", "")?,
+            rest_rs = pattern_for_range(2, to, "r", "")?,
+            rest_es = pattern_for_range(2, to, "e", "")?,
+            es = pattern_for(to, "e", "")?,
+        )
+    }
+}
+
+pub(crate) fn tuple_flatten(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut flatten_bounds = String::new();
+    for i in 1..=to {
+        write!(flatten_bounds, "\n    I{0}: TupleFlatten<I{0}>,", i)?;
+    }
+    write!(
+        dest,
+        "\
+impl<{args}> TupleFlatten<({args})> for ({args})
+where{flatten_bounds}
+    ({flattened_args}): TupleConcatMany<({flattened_args})>,
+{{
+    type Type = ConcatMany<({flattened_args})>;
+
+    #[inline(always)]
+    fn flatten_tuple(tpl: ({args})) -> Self::Type {{
+        let ({vals}) = tpl;
+        concat_many(({flattened_vals}))
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        flatten_bounds = flatten_bounds,
+        flattened_args = pattern_for(to, "Flatten<I", ">")?,
+        flattened_vals = pattern_for(to, "flatten_tuple(i", ")")?,
+    )
+}
+
+pub(crate) fn tuple_fold(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut folds = String::new();
+    let mut rfolds = String::new();
+    for i in 1..=to {
+        write!(folds, "\n        let acc = folder.fold_elem(acc, i{});", i)?;
+        write!(
+            rfolds,
+            "\n        let acc = folder.fold_elem(acc, i{});",
+            to + 1 - i
+        )?;
+    }
+    write!(
+        dest,
+        "\
+impl<Folder: TupleFolder, {args}> TupleFold<Folder, ({args})> for (Folder, ({args})) {{
+    #[inline(always)]
+    fn fold_tuple(mut folder: Folder, acc: Folder::Acc, tpl: ({args})) -> Folder::Acc {{
+        let ({vals}) = tpl;{folds}
+        acc
+    }}
+
+    #[inline(always)]
+    fn rfold_tuple(mut folder: Folder, acc: Folder::Acc, tpl: ({args})) -> Folder::Acc {{
+        let ({vals}) = tpl;{rfolds}
+        acc
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        folds = folds,
+        rfolds = rfolds,
+    )
+}
+
+pub(crate) fn tuple_for_each(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut visits = String::new();
+    for i in 1..=to {
+        write!(visits, "\n        visitor.visit(i{});", i)?;
+    }
+    write!(
+        dest,
+        "\
+impl<Visitor: TupleVisitor, {args}> TupleForEach<Visitor, ({args})> for (Visitor, ({args})) {{
+    #[inline(always)]
+    fn for_each_tuple(mut visitor: Visitor, tpl: ({args})) {{
+        let ({vals}) = tpl;{visits}
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        visits = visits,
+    )
+}
+
+pub(crate) fn tuple_get(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let args = pattern_for(to, "I", "")?;
+    for index in 0..to {
+        if index > 0 {
+            dest.push_str("\n\n");
+        }
+        write!(
+            dest,
+            "\
+impl<{args}> TupleGet<{index}> for ({args}) {{
+    type Type = I{elem};
+
+    #[inline(always)]
+    fn get(tpl: Self) -> Self::Type {{
+        tpl.{index}
+    }}
+
+    #[inline(always)]
+    fn get_ref(tpl: &Self) -> &Self::Type {{
+        &tpl.{index}
+    }}
+
+    #[inline(always)]
+    fn get_mut(tpl: &mut Self) -> &mut Self::Type {{
+        &mut tpl.{index}
+    }}
+}}",
+            args = args,
+            index = index,
+            elem = index + 1,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn tuple_insert_at(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let args = pattern_for(to, "I", "")?;
+    let vals = pattern_for(to, "i", "")?;
+    for index in 0..=to {
+        if index > 0 {
+            dest.push_str("\n\n");
+        }
+        write!(
+            dest,
+            "\
+impl<Elem, {args}> TupleInsertAt<Elem, {index}> for ({args}) {{
+    type Type = ({front_args} Elem, {back_args});
+
+    #[inline(always)]
+    fn insert_at(tpl: Self, elem: Elem) -> Self::Type {{
+        let ({vals}) = tpl;
+        ({front_vals} elem, {back_vals})
+    }}
+}}",
+            args = args,
+            vals = vals,
+            index = index,
+            front_args = pattern_for_range(1, index, "I", "")?,
+            back_args = pattern_for_range(index + 1, to, "I", "")?,
+            front_vals = pattern_for_range(1, index, "i", "")?,
+            back_vals = pattern_for_range(index + 1, to, "i", "")?,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn tuple_iter(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let elems = "T, ".repeat(to);
+    write!(
+        dest,
+        "\
+impl<T> TupleIter<({elems})> for ({elems}) {{
+    type Type = ::core::array::IntoIter<T, {to}>;
+
+    #[inline(always)]
+    fn iter_tuple(tpl: ({elems})) -> Self::Type {{
+        IntoIterator::into_iter(tuple_to_array(tpl))
+    }}
+}}",
+        elems = elems.trim_end(),
+        to = to,
+    )
+}
+
+pub(crate) fn tuple_join(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut pins = String::new();
+    let mut polls = String::new();
+    let mut try_polls = String::new();
+    let mut try_bounds = String::new();
+    for i in 1..=to {
+        write!(
+            try_bounds,
+            "
+    F{0}: Future<Output = Result<I{0}, E>>,",
+            i,
+        )?;
+        write!(
+            pins,
+            "
+            let mut f{0} = pin!(f{0});
+            let mut o{0} = None;",
+            i,
+        )?;
+        write!(
+            polls,
+            "
+                if o{0}.is_none() {{
+                    match f{0}.as_mut().poll(cx) {{
+                        Poll::Ready(value) => o{0} = Some(value),
+                        Poll::Pending => pending = true,
+                    }}
+                }}",
+            i,
+        )?;
+        write!(
+            try_polls,
+            "
+                if o{0}.is_none() {{
+                    match f{0}.as_mut().poll(cx) {{
+                        Poll::Ready(Ok(value)) => o{0} = Some(value),
+                        Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                        Poll::Pending => pending = true,
+                    }}
+                }}",
+            i,
+        )?;
+    }
+    write!(
+        dest,
+        "\
+impl<{futures}> TupleJoin<({args})> for ({args}) {{
+    type Type = ({outputs});
+
+    #[inline(always)]
+    fn join_tuple(tpl: ({args})) -> impl Future<Output = Self::Type> {{
+        async move {{
+            let ({vals}) = tpl;{pins}
+            poll_fn(|cx| {{
+                let mut pending = false;{polls}
+                if pending {{
+                    return Poll::Pending;
+                }}
+                Poll::Ready(({takes}))
+            }})
+            .await
+        }}
+    }}
+}}
+
+impl<E, {generics}> TupleTryJoin<({args})> for ({args})
+where{try_bounds}
+{{
+    type Type = ({oks});
+    type Error = E;
+
+    #[inline(always)]
+    fn try_join_tuple(tpl: ({args})) -> impl Future<Output = Result<Self::Type, E>> {{
+        async move {{
+            let ({vals}) = tpl;{pins}
+            poll_fn(|cx| {{
+                let mut pending = false;{try_polls}
+                if pending {{
+                    return Poll::Pending;
+                }}
+                Poll::Ready(Ok(({takes})))
+            }})
+            .await
+        }}
+    }}
+}}",
+        futures = pattern_for(to, "F", ": Future")?,
+        args = pattern_for(to, "F", "")?,
+        outputs = pattern_for(to, "<F", " as Future>::Output")?,
+        vals = pattern_for(to, "f", "")?,
+        pins = pins,
+        polls = polls,
+        takes = pattern_for(to, "o", ".take().unwrap()")?,
+        generics = pattern_for2(to, "F", ", I", "")?,
+        try_bounds = try_bounds,
+        oks = pattern_for(to, "I", "")?,
+        try_polls = try_polls,
+    )
+}
+
 pub(crate) fn tuple_length(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
     write!(
         dest,
@@ -175,6 +779,97 @@ impl<Mapper: TupleMapper, {args}> TupleMap<Mapper, ({args})> for (Mapper, ({args
     )
 }
 
+pub(crate) fn tuple_map_homogeneous(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let elems = "T, ".repeat(to);
+    let mapped = "U, ".repeat(to);
+    let mut indexed_vals = String::new();
+    for i in 1..=to {
+        if !indexed_vals.is_empty() {
+            indexed_vals.push(' ');
+        }
+        write!(indexed_vals, "f({}, i{}),", i - 1, i)?;
+    }
+    write!(
+        dest,
+        "\
+impl<T> TupleMapHomogeneous<({elems})> for ({elems}) {{
+    type Elem = T;
+    type Type<U> = ({mapped});
+
+    #[inline(always)]
+    fn map_homogeneous<U, F: FnMut(T) -> U>(tpl: ({elems}), mut f: F) -> Self::Type<U> {{
+        let ({vals}) = tpl;
+        ({mapped_vals})
+    }}
+
+    #[inline(always)]
+    fn map_indexed<U, F: FnMut(usize, T) -> U>(tpl: ({elems}), mut f: F) -> Self::Type<U> {{
+        let ({vals}) = tpl;
+        ({indexed_vals})
+    }}
+}}",
+        elems = elems.trim_end(),
+        mapped = mapped.trim_end(),
+        vals = pattern_for(to, "i", "")?,
+        mapped_vals = pattern_for(to, "f(i", ")")?,
+        indexed_vals = indexed_vals,
+    )
+}
+
+pub(crate) fn tuple_map_mut(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<'a, Mapper: TupleMutMapper<'a>, {bounds}> TupleMapMut<'a, Mapper, ({ref_args})>
+    for (Mapper, ({ref_args}))
+{{
+    type Type = ({mapped_args});
+
+    #[inline(always)]
+    fn map_mut_tuple(mut mapper: Mapper, tpl: ({ref_args})) -> Self::Type {{
+        let ({vals}) = tpl;
+        ({mapped_vals})
+    }}
+}}",
+        bounds = pattern_for(to, "I", ": 'a")?,
+        ref_args = pattern_for(to, "&'a mut I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        mapped_args = pattern_for(to, "<Mapper as TupleMutMapper<'a>>::MapElem::<I", ">")?,
+        mapped_vals = pattern_for(
+            to,
+            "<Mapper as TupleMutMapper<'a>>::map_elem(&mut mapper, i",
+            ")"
+        )?,
+    )
+}
+
+pub(crate) fn tuple_map_ref(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<'a, Mapper: TupleRefMapper<'a>, {bounds}> TupleMapRef<'a, Mapper, ({ref_args})>
+    for (Mapper, ({ref_args}))
+{{
+    type Type = ({mapped_args});
+
+    #[inline(always)]
+    fn map_ref_tuple(mut mapper: Mapper, tpl: ({ref_args})) -> Self::Type {{
+        let ({vals}) = tpl;
+        ({mapped_vals})
+    }}
+}}",
+        bounds = pattern_for(to, "I", ": 'a")?,
+        ref_args = pattern_for(to, "&'a I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        mapped_args = pattern_for(to, "<Mapper as TupleRefMapper<'a>>::MapElem::<I", ">")?,
+        mapped_vals = pattern_for(
+            to,
+            "<Mapper as TupleRefMapper<'a>>::map_elem(&mut mapper, i",
+            ")"
+        )?,
+    )
+}
+
 pub(crate) fn tuple_option(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
     let nones = "None, ".repeat(to);
     write!(
@@ -254,6 +949,337 @@ impl<'a, {args}> TupleRef<'a, ({args})> for &'a ({args}) {{
     )
 }
 
+pub(crate) fn tuple_remove_at(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let args = pattern_for(to, "I", "")?;
+    let vals = pattern_for(to, "i", "")?;
+    for index in 0..to {
+        if index > 0 {
+            dest.push_str("\n\n");
+        }
+        write!(
+            dest,
+            "\
+impl<{args}> TupleRemoveAt<{index}> for ({args}) {{
+    type Elem = I{elem};
+    type Rest = ({front_args} {back_args});
+
+    #[inline(always)]
+    fn remove_at(tpl: Self) -> (Self::Elem, Self::Rest) {{
+        let ({vals}) = tpl;
+        (i{elem}, ({front_vals} {back_vals}))
+    }}
+}}",
+            args = args,
+            vals = vals,
+            index = index,
+            elem = index + 1,
+            front_args = pattern_for_range(1, index, "I", "")?,
+            back_args = pattern_for_range(index + 2, to, "I", "")?,
+            front_vals = pattern_for_range(1, index, "i", "")?,
+            back_vals = pattern_for_range(index + 2, to, "i", "")?,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn tuple_replace_at(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let args = pattern_for(to, "I", "")?;
+    let vals = pattern_for(to, "i", "")?;
+    for index in 0..to {
+        if index > 0 {
+            dest.push_str("\n\n");
+        }
+        write!(
+            dest,
+            "\
+impl<New, {args}> TupleReplaceAt<New, {index}> for ({args}) {{
+    type Type = ({front_args} New, {back_args});
+    type Old = I{elem};
+
+    #[inline(always)]
+    fn replace_at(tpl: Self, new: New) -> (Self::Type, Self::Old) {{
+        let ({vals}) = tpl;
+        (({front_vals} new, {back_vals}), i{elem})
+    }}
+}}",
+            args = args,
+            vals = vals,
+            index = index,
+            elem = index + 1,
+            front_args = pattern_for_range(1, index, "I", "")?,
+            back_args = pattern_for_range(index + 2, to, "I", "")?,
+            front_vals = pattern_for_range(1, index, "i", "")?,
+            back_vals = pattern_for_range(index + 2, to, "i", "")?,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn tuple_result(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let errs = "Err(err.clone()), ".repeat(to - 1);
+    write!(
+        dest,
+        "\
+impl<E: Clone, {args}> TupleResult<({args}), E> for ({args}) {{
+    type Type = ({results});
+
+    #[inline(always)]
+    fn result_tuple(tpl: Result<({args}), E>) -> Self::Type {{
+        match tpl {{
+            Ok(({vals})) => ({oks}),
+            Err(err) => ({errs}Err(err),),
+        }}
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        results = pattern_for(to, "Result<I", ", E>")?,
+        vals = pattern_for(to, "i", "")?,
+        oks = pattern_for(to, "Ok(i", ")")?,
+        errs = errs,
+    )
+}
+
+pub(crate) fn tuple_reverse(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<{args}> TupleReverse<({args})> for ({args}) {{
+    type Type = ({rev_args});
+
+    #[inline(always)]
+    fn reverse_tuple(tpl: ({args})) -> Self::Type {{
+        let ({vals}) = tpl;
+        ({rev_vals})
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        rev_args = pattern_rev(to, "I", "")?,
+        rev_vals = pattern_rev(to, "i", "")?,
+    )
+}
+
+pub(crate) fn tuple_rotate(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let args = pattern_for(to, "I", "")?;
+    let vals = pattern_for(to, "i", "")?;
+    for amount in 0..=to {
+        if amount > 0 {
+            dest.push_str("\n\n");
+        }
+        write!(
+            dest,
+            "\
+impl<{args}> TupleRotateLeft<{amount}> for ({args}) {{
+    type Type = ({left_back_args} {left_front_args});
+
+    #[inline(always)]
+    fn rotate_left(tpl: Self) -> Self::Type {{
+        let ({vals}) = tpl;
+        ({left_back_vals} {left_front_vals})
+    }}
+}}
+
+impl<{args}> TupleRotateRight<{amount}> for ({args}) {{
+    type Type = ({right_back_args} {right_front_args});
+
+    #[inline(always)]
+    fn rotate_right(tpl: Self) -> Self::Type {{
+        let ({vals}) = tpl;
+        ({right_back_vals} {right_front_vals})
+    }}
+}}",
+            args = args,
+            vals = vals,
+            amount = amount,
+            left_front_args = pattern_for_range(1, amount, "I", "")?,
+            left_back_args = pattern_for_range(amount + 1, to, "I", "")?,
+            left_front_vals = pattern_for_range(1, amount, "i", "")?,
+            left_back_vals = pattern_for_range(amount + 1, to, "i", "")?,
+            right_front_args = pattern_for_range(1, to - amount, "I", "")?,
+            right_back_args = pattern_for_range(to - amount + 1, to, "I", "")?,
+            right_front_vals = pattern_for_range(1, to - amount, "i", "")?,
+            right_back_vals = pattern_for_range(to - amount + 1, to, "i", "")?,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn tuple_split_at(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let args = pattern_for(to, "I", "")?;
+    let vals = pattern_for(to, "i", "")?;
+    for index in 0..=to {
+        if index > 0 {
+            dest.push_str("\n\n");
+        }
+        write!(
+            dest,
+            "\
+impl<{args}> TupleSplitAt<{index}> for ({args}) {{
+    type Front = ({front_args});
+    type Back = ({back_args});
+
+    #[inline(always)]
+    fn split_at(tpl: Self) -> (Self::Front, Self::Back) {{
+        let ({vals}) = tpl;
+        (({front_vals}), ({back_vals}))
+    }}
+}}",
+            args = args,
+            vals = vals,
+            index = index,
+            front_args = pattern_for_range(1, index, "I", "")?,
+            back_args = pattern_for_range(index + 1, to, "I", "")?,
+            front_vals = pattern_for_range(1, index, "i", "")?,
+            back_vals = pattern_for_range(index + 1, to, "i", "")?,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn tuple_transpose(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut prepend_bounds = String::new();
+    for i in 1..=to {
+        write!(
+            prepend_bounds,
+            "\n    (I{0}, R{0}): TuplePrepend<I{0}, R{0}>,",
+            i,
+        )?;
+    }
+    write!(
+        dest,
+        "\
+impl<{args}> TupleTranspose<(({args}),)> for (({args}),) {{
+    type Type = ({singles});
+
+    #[inline(always)]
+    fn transpose_tuple(tpl: (({args}),)) -> Self::Type {{
+        let (({vals}),) = tpl;
+        ({single_vals})
+    }}
+}}
+
+impl<{args} {tails}> TupleZipPrepend<({args}), ({tails})> for (({args}), ({tails}))
+where{prepend_bounds}
+{{
+    type Type = ({prepended});
+
+    #[inline(always)]
+    fn zip_prepend(heads: ({args}), tails: ({tails})) -> Self::Type {{
+        let ({vals}) = heads;
+        let ({tail_vals}) = tails;
+        ({prepended_vals})
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        singles = pattern_for(to, "(I", ",)")?,
+        single_vals = pattern_for(to, "(i", ",)")?,
+        tails = pattern_for(to, "R", "")?,
+        tail_vals = pattern_for(to, "r", "")?,
+        prepend_bounds = prepend_bounds,
+        prepended = pattern_for2(to, "Prepend<I", ", R", ">")?,
+        prepended_vals = pattern_for2(to, "prepend(i", ", r", ")")?,
+    )?;
+    if to >= 2 {
+        write!(
+            dest,
+            "\n\n\
+impl<Head, {args}> TupleTranspose<(Head, {args})> for (Head, {args})
+where
+    ({args}): TupleTranspose<({args})>,
+    (Head, Transpose<({args})>): TupleZipPrepend<Head, Transpose<({args})>>,
+{{
+    type Type = <(Head, Transpose<({args})>) as TupleZipPrepend<Head, Transpose<({args})>>>::Type;
+
+    #[inline(always)]
+    fn transpose_tuple(tpl: (Head, {args})) -> Self::Type {{
+        let (head, {vals}) = tpl;
+        <(Head, Transpose<({args})>) as TupleZipPrepend<Head, Transpose<({args})>>>::zip_prepend(
+            head,
+            transpose_tuple(({vals})),
+        )
+    }}
+}}",
+            args = pattern_for(to - 1, "I", "")?,
+            vals = pattern_for(to - 1, "i", "")?,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn tuple_try_all(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut generics = String::new();
+    let mut bounds = String::new();
+    for i in 1..=to {
+        if !generics.is_empty() {
+            generics.push(' ');
+        }
+        write!(generics, "F{0}, I{0}, E{0},", i)?;
+        write!(
+            bounds,
+            "
+    F{0}: FnOnce() -> Result<I{0}, E{0}>,",
+            i,
+        )?;
+        if i > 1 {
+            write!(
+                bounds,
+                "
+    E1: From<E{0}>,",
+                i,
+            )?;
+        }
+    }
+    write!(
+        dest,
+        "\
+impl<{generics}> TupleTryAll<({thunks})> for ({thunks})
+where{bounds}
+{{
+    type Type = ({args});
+    type Error = E1;
+
+    #[inline(always)]
+    fn try_all(tpl: ({thunks})) -> Result<Self::Type, E1> {{
+        let ({thunk_vals}) = tpl;
+        Ok(({calls}))
+    }}
+}}",
+        generics = generics,
+        thunks = pattern_for(to, "F", "")?,
+        bounds = bounds,
+        args = pattern_for(to, "I", "")?,
+        thunk_vals = pattern_for(to, "f", "")?,
+        calls = pattern_for(to, "f", "()?")?,
+    )
+}
+
+pub(crate) fn tuple_try_map(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<Mapper: TupleTryMapper, {args}> TupleTryMap<Mapper, ({args})> for (Mapper, ({args})) {{
+    type Type = ({mapped_args});
+
+    fn try_map_tuple(
+        mut mapper: Mapper,
+        tpl: ({args}),
+    ) -> Result<Self::Type, <Mapper as TupleTryMapper>::Error> {{
+        let ({vals}) = tpl;
+        Ok(({mapped_vals}))
+    }}
+}}",
+        args = pattern_for(to, "I", "")?,
+        vals = pattern_for(to, "i", "")?,
+        mapped_args = pattern_for(to, "<Mapper as TupleTryMapper>::MapElem::<I", ">")?,
+        mapped_vals = pattern_for(
+            to,
+            "<Mapper as TupleTryMapper>::try_map_elem(&mut mapper, i",
+            ")?"
+        )?,
+    )
+}
+
 pub(crate) fn tuple_tuple(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
     write!(
         dest,
@@ -301,3 +1327,27 @@ impl<Elem, {args}> TupleUnprepend<(Elem, {args})> for (Elem, {args}) {{
         vals = pattern_for(to, "i", "")?,
     )
 }
+
+pub(crate) fn tuple_unzip(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<{args}> TupleUnzip<({pairs})> for ({pairs}) {{
+    type Left = ({left_args});
+    type Right = ({right_args});
+
+    #[inline(always)]
+    fn unzip_tuple(tpl: ({pairs})) -> (Self::Left, Self::Right) {{
+        let ({pair_vals}) = tpl;
+        (({left_vals}), ({right_vals}))
+    }}
+}}",
+        args = pattern_for2(to, "A", ", B", "")?,
+        pairs = pattern_for2(to, "(A", ", B", ")")?,
+        pair_vals = pattern_for2(to, "(a", ", b", ")")?,
+        left_args = pattern_for(to, "A", "")?,
+        right_args = pattern_for(to, "B", "")?,
+        left_vals = pattern_for(to, "a", "")?,
+        right_vals = pattern_for(to, "b", "")?,
+    )
+}
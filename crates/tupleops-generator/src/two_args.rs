@@ -0,0 +1,74 @@
+use std::fmt::Write;
+
+use crate::common::{pattern_for, pattern_for2};
+
+pub(crate) fn tuple_zip(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    write!(
+        dest,
+        "\
+impl<{args}> TupleZip<({left_args}), ({right_args})> for (({left_args}), ({right_args})) {{
+    type Type = ({pairs});
+
+    #[inline(always)]
+    fn zip_tuples(left: ({left_args}), right: ({right_args})) -> Self::Type {{
+        let ({left_vals}) = left;
+        let ({right_vals}) = right;
+        ({pair_vals})
+    }}
+}}",
+        args = pattern_for2(to, "A", ", B", "")?,
+        left_args = pattern_for(to, "A", "")?,
+        right_args = pattern_for(to, "B", "")?,
+        left_vals = pattern_for(to, "a", "")?,
+        right_vals = pattern_for(to, "b", "")?,
+        pairs = pattern_for2(to, "(A", ", B", ")")?,
+        pair_vals = pattern_for2(to, "(a", ", b", ")")?,
+    )
+}
+
+pub(crate) fn tuple_zip_with(dest: &mut String, to: usize) -> Result<(), std::fmt::Error> {
+    let mut bounds = String::new();
+    for i in 1..=to {
+        if !bounds.is_empty() {
+            bounds.push_str(" + ");
+        }
+        write!(bounds, "TupleBiMapper<A{0}, B{0}>", i)?;
+    }
+    let mut mapped_vals = String::new();
+    for i in 1..=to {
+        if !mapped_vals.is_empty() {
+            mapped_vals.push(' ');
+        }
+        write!(
+            mapped_vals,
+            "<Mapper as TupleBiMapper<A{0}, B{0}>>::map_elems(&mut mapper, a{0}, b{0}),",
+            i,
+        )?;
+    }
+    write!(
+        dest,
+        "\
+impl<Mapper, {args}> TupleZipWith<Mapper, ({left_args}), ({right_args})>
+    for (Mapper, ({left_args}), ({right_args}))
+where
+    Mapper: {bounds},
+{{
+    type Type = ({mapped_args});
+
+    #[inline(always)]
+    fn zip_with(mut mapper: Mapper, left: ({left_args}), right: ({right_args})) -> Self::Type {{
+        let ({left_vals}) = left;
+        let ({right_vals}) = right;
+        ({mapped_vals})
+    }}
+}}",
+        args = pattern_for2(to, "A", ", B", "")?,
+        left_args = pattern_for(to, "A", "")?,
+        right_args = pattern_for(to, "B", "")?,
+        left_vals = pattern_for(to, "a", "")?,
+        right_vals = pattern_for(to, "b", "")?,
+        bounds = bounds,
+        mapped_args = pattern_for2(to, "<Mapper as TupleBiMapper<A", ", B", ">>::MapElem")?,
+        mapped_vals = mapped_vals,
+    )
+}
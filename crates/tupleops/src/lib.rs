@@ -12,14 +12,6 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 #![no_std]
-#![cfg_attr(
-    feature = "feature-const_fn_trait_bound",
-    feature(const_fn_trait_bound)
-)]
-#![cfg_attr(
-    feature = "feature-generic_associated_types",
-    feature(generic_associated_types)
-)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 //! Utility library to work with tuples.
@@ -48,6 +40,36 @@
 //!   );
 //!   ```
 //!
+//! * **Collect all errors of a tuple of [Result]s: [collect_errors()]**
+//!
+//!   `features = ["collect-errors"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::collect_errors;
+//!   let tpl: (Result<u8, &str>, Result<u16, ()>, Result<u32, ()>) = (Err("one"), Ok(2), Err(()));
+//!   assert_eq!(collect_errors(tpl), Err((Some("one"), None, Some(()))));
+//!   ```
+//!
+//! * **Return the first [Ok] of a tuple of [Result]s: [first_ok()], [first_ok_either()]**
+//!
+//!   `features = ["first-ok"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::first_ok;
+//!   let tpl: (Result<u8, &str>, Result<u8, ()>) = (Err("one"), Ok(2));
+//!   assert_eq!(first_ok(tpl), Ok((1, 2)));
+//!   ```
+//!
+//! * **Lazily call a tuple of fallible closures: [try_all()]**
+//!
+//!   `features = ["try-all"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::try_all;
+//!   let result = try_all((|| "1".parse::<u8>(), || "2".parse::<u16>()));
+//!   assert_eq!(result, Ok((1, 2)));
+//!   ```
+//!
 //! * **Test if all elements are [Some]: [all_some()]**
 //!
 //!   `features = ["all-some"]`, included by default
@@ -64,6 +86,29 @@
 //!   );
 //!   ```
 //!
+//! * **Test if any element is [Some]: [any_some()], [count_some()]**
+//!
+//!   `features = ["any-some"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{any_some, count_some};
+//!   assert!(any_some(&(None::<u8>, Some(2), None::<u32>)));
+//!   assert_eq!(count_some(&(Some(1), None::<u16>, Some(3))), 2);
+//!   ```
+//!
+//! * **Test if all elements are [Ok] or [Some] with an error type:
+//!   [try_all_ok()], [try_all_some()]**
+//!
+//!   `features = ["all-ok-error", "all-some-error"]`, included by default.
+//!   The errors implement `core::error::Error` with `features = ["core-error"]`,
+//!   which is **not** included by default, because it needs Rust 1.81.
+//!
+//!   ```
+//!   # use tupleops::try_all_some;
+//!   let err = try_all_some((Some(1), None::<u16>, None::<u32>)).unwrap_err();
+//!   assert_eq!(err.to_string(), "not all tuple elements are Some, failed positions: [1, 2]");
+//!   ```
+//!
 //! * **Prepend an element to a tuple: [prepend()]**
 //!
 //!   `features = ["prepend"]`, included by default
@@ -100,6 +145,121 @@
 //!   assert_eq!(concat_many(((), (1,), (2, 3,), (4, 5, 6))), (1, 2, 3, 4, 5, 6));
 //!   ```
 //!
+//! * **Split a tuple at an index: `split_at()`**
+//!
+//!   `features = ["split-at"]`, **not** included by default,
+//!   because it generates one implementation per position and tuple length.
+//!
+//!   ```
+//!   # #[cfg(feature = "split-at")] {
+//!   # use tupleops::split_at;
+//!   assert_eq!(split_at::<2, _>((1, 2, 3, 4, 5)), ((1, 2), (3, 4, 5)));
+//!   # }
+//!   ```
+//!
+//! * **Recursively flatten nested tuples: [flatten_tuple()]**
+//!
+//!   `features = ["flatten"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::flatten_tuple;
+//!   assert_eq!(flatten_tuple((1, (2, (3, 4)), 5)), (1, 2, 3, 4, 5));
+//!   ```
+//!
+//! * **Get the N-th element of a tuple: `get()`, `get_ref()`, `get_mut()`**
+//!
+//!   `features = ["get"]`, **not** included by default,
+//!   because it generates one implementation per position and tuple length.
+//!
+//!   ```
+//!   # #[cfg(feature = "get")] {
+//!   # use tupleops::get;
+//!   assert_eq!(get::<1, _>((1, 2, 3)), 2);
+//!   # }
+//!   ```
+//!
+//! * **Insert an element into a tuple: `insert_at()`**
+//!
+//!   `features = ["insert-at"]`, **not** included by default,
+//!   because it generates one implementation per position and tuple length.
+//!
+//!   ```
+//!   # #[cfg(feature = "insert-at")] {
+//!   # use tupleops::insert_at;
+//!   assert_eq!(insert_at::<1, _, _>((1, 3), 2), (1, 2, 3));
+//!   # }
+//!   ```
+//!
+//! * **Remove an element from a tuple: `remove_at()`**
+//!
+//!   `features = ["remove-at"]`, **not** included by default,
+//!   because it generates one implementation per position and tuple length.
+//!
+//!   ```
+//!   # #[cfg(feature = "remove-at")] {
+//!   # use tupleops::remove_at;
+//!   assert_eq!(remove_at::<1, _>((1, 2, 3)), (2, (1, 3)));
+//!   # }
+//!   ```
+//!
+//! * **Replace an element of a tuple: `replace_at()`**
+//!
+//!   `features = ["replace-at"]`, **not** included by default,
+//!   because it generates one implementation per position and tuple length.
+//!
+//!   ```
+//!   # #[cfg(feature = "replace-at")] {
+//!   # use tupleops::replace_at;
+//!   assert_eq!(replace_at::<1, _, _>((1, 2, 3), "two"), ((1, "two", 3), 2));
+//!   # }
+//!   ```
+//!
+//! * **Zip two tuples element-wise: [zip_tuples()]**
+//!
+//!   `features = ["zip"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::zip_tuples;
+//!   assert_eq!(zip_tuples((1, 2), ("a", "b")), ((1, "a"), (2, "b")));
+//!   ```
+//!
+//! * **Combine two tuples element-wise with a mapper: [zip_with()]**
+//!
+//!   `features = ["zip-with"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{TupleBiMapper, zip_with};
+//!   struct Override;
+//!
+//!   impl<T> TupleBiMapper<T, Option<T>> for Override {
+//!       type MapElem = T;
+//!
+//!       fn map_elems(&mut self, default: T, value: Option<T>) -> T {
+//!           value.unwrap_or(default)
+//!       }
+//!   }
+//!
+//!   assert_eq!(zip_with(Override, (1, "two"), (Some(10), None)), (10, "two"));
+//!   ```
+//!
+//! * **Unzip a tuple of pairs: [unzip_tuple()]**
+//!
+//!   `features = ["unzip"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::unzip_tuple;
+//!   assert_eq!(unzip_tuple(((1, "a"), (2, "b"))), ((1, 2), ("a", "b")));
+//!   ```
+//!
+//! * **Transpose a tuple of tuples: [transpose_tuple()]**
+//!
+//!   `features = ["transpose"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::transpose_tuple;
+//!   assert_eq!(transpose_tuple(((1, 2), (3, 4), (5, 6))), ((1, 3, 5), (2, 4, 6)));
+//!   ```
+//!
 //! * **Turn a reference to a tuple to a tuple of references: [ref_tuple()]**
 //!
 //!   `features = ["ref"]`, included by default
@@ -118,6 +278,37 @@
 //!   assert_eq!(ref_mut_tuple(&mut (1, 2, 3)), (&mut 1, &mut 2, &mut 3));
 //!   ```
 //!
+//! * **Convert between tuples and arrays: [tuple_to_array()], [array_to_tuple()]**
+//!
+//!   `features = ["array"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{array_to_tuple, tuple_to_array};
+//!   assert_eq!(tuple_to_array((1, 2, 3)), [1, 2, 3]);
+//!   assert_eq!(array_to_tuple([1, 2, 3]), (1, 2, 3));
+//!   ```
+//!
+//! * **Iterate over the elements of a tuple: [iter_tuple()]**
+//!
+//!   `features = ["iter"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::iter_tuple;
+//!   assert_eq!(iter_tuple((1, 2, 3)).sum::<i32>(), 6);
+//!   ```
+//!
+//! * **Iterate over the elements of a tuple as trait objects: [iter_dyn()], [TupleIterDyn]**
+//!
+//!   `features = ["dyn"]`, included by default
+//!
+//!   ```
+//!   # use core::fmt::Debug;
+//!   # use tupleops::TupleIterDyn;
+//!   for elem in (1, "two", 3.0).iter_dyn::<dyn Debug>() {
+//!       println!("{:?}", elem);
+//!   }
+//!   ```
+//!
 //! * **Extract the first element of a tuple: [unprepend()]**
 //!
 //!   `features = ["unprepend"]`, included by default
@@ -136,6 +327,40 @@
 //!   assert_eq!(unappend((1, 2, 3, 4)), ((1, 2, 3), 4));
 //!   ```
 //!
+//! * **Reverse the order of the elements of a tuple: [reverse_tuple()]**
+//!
+//!   `features = ["reverse"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::reverse_tuple;
+//!   assert_eq!(reverse_tuple((1, 2, 3, 4)), (4, 3, 2, 1));
+//!   ```
+//!
+//! * **Rotate the elements of a tuple: `rotate_left()`, `rotate_right()`**
+//!
+//!   `features = ["rotate"]`, **not** included by default,
+//!   because it generates one implementation per rotation amount and tuple length.
+//!
+//!   ```
+//!   # #[cfg(feature = "rotate")] {
+//!   # use tupleops::{rotate_left, rotate_right};
+//!   assert_eq!(rotate_left::<1, _>((1, 2, 3, 4)), (2, 3, 4, 1));
+//!   assert_eq!(rotate_right::<1, _>((1, 2, 3, 4)), (4, 1, 2, 3));
+//!   # }
+//!   ```
+//!
+//! * **Swap two elements of a tuple: `swap()`**
+//!
+//!   `features = ["swap"]`, **not** included by default,
+//!   because it is implemented with `replace_at()`, which implies `features = ["replace-at"]`.
+//!
+//!   ```
+//!   # #[cfg(feature = "swap")] {
+//!   # use tupleops::swap;
+//!   assert_eq!(swap::<0, 2, _>((1, "two", 3.0)), (3.0, "two", 1));
+//!   # }
+//!   ```
+//!
 //! * **Call a function with the tuple members as arguments: [apply()]**
 //!
 //!   `features = ["apply"]`, included by default
@@ -157,6 +382,71 @@
 //!   assert_eq!(option_tuple(Some((1, 2, 3))), (Some(1), Some(2), Some(3)));
 //!   ```
 //!
+//! * **Element-wise wrap the element of a tuple in [Result]: [result_tuple()]**
+//!
+//!   `features = ["result"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::result_tuple;
+//!   assert_eq!(result_tuple(Err::<(u8, u16), _>("e")), (Err("e"), Err("e")));
+//!   ```
+//!
+//! * **Turn a tuple of [Option]s into an [Option] of a tuple: [collect_option()]**
+//!
+//!   `features = ["collect-option"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::collect_option;
+//!   assert_eq!(collect_option((Some(1), Some("two"))), Some((1, "two")));
+//!   ```
+//!
+//! * **Turn a tuple of [Result]s into a [Result] of a tuple: [collect_result()]**
+//!
+//!   `features = ["collect-result"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::collect_result;
+//!   assert_eq!(collect_result((Ok(1), Err::<u8, _>("two"), Ok(3))), Err("two"));
+//!   ```
+//!
+//! * **Fold the elements of a tuple: [fold_tuple()], [rfold_tuple()]**
+//!
+//!   `features = ["fold"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{fold_tuple, TupleFolder};
+//!   struct SizeOf;
+//!
+//!   impl TupleFolder for SizeOf {
+//!       type Acc = usize;
+//!
+//!       fn fold_elem<Elem>(&mut self, acc: usize, _: Elem) -> usize {
+//!           acc + core::mem::size_of::<Elem>()
+//!       }
+//!   }
+//!
+//!   assert_eq!(fold_tuple(SizeOf, 0, (1_u8, 2_u16, 3_u64)), 11);
+//!   ```
+//!
+//! * **Visit every element of a tuple: [for_each_tuple()]**
+//!
+//!   `features = ["for-each"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{for_each_tuple, TupleVisitor};
+//!   struct Count(usize);
+//!
+//!   impl TupleVisitor for Count {
+//!       fn visit<Elem>(&mut self, _: Elem) {
+//!           self.0 += 1;
+//!       }
+//!   }
+//!
+//!   let mut count = Count(0);
+//!   for_each_tuple(&mut count, (1, "two", 3.0));
+//!   assert_eq!(count.0, 3);
+//!   ```
+//!
 //! * **Get the length of a tuple: [length()]**
 //!
 //!   `features = ["length"]`, included by default
@@ -168,18 +458,15 @@
 //!
 //! * **Map a tuple: [map_tuple()]**
 //!
-//!   `features = ["[map](map_tuple)"]`, **not** included by default,
-//!   because it needs the unstable feature
-//!   [`generic_associated_types` (GAT)](https://github.com/rust-lang/rust/issues/44265).
+//!   `features = ["map"]`, included by default
 //!
 //!   ```
-//!   # #![feature(generic_associated_types)]
 //!   # use tupleops::{TupleMapper, map_tuple};
 //!   struct MyTupleEnum(usize);
 //!
 //!   impl TupleMapper for MyTupleEnum {
 //!       type MapElem<Type> = (usize, Type);
-//!  
+//!
 //!       fn map_elem<Elem>(&mut self, elem: Elem) -> Self::MapElem<Elem> {
 //!           let index = self.0;
 //!           self.0 += 1;
@@ -193,6 +480,106 @@
 //!   )
 //!   ```
 //!
+//! * **Map a homogeneous tuple with a closure: [map_homogeneous()], [map_indexed()]**
+//!
+//!   `features = ["map-homogeneous"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{map_homogeneous, map_indexed};
+//!   assert_eq!(map_homogeneous((1, 2, 3), |x| x * 10), (10, 20, 30));
+//!   assert_eq!(map_indexed((1, 2, 3), |i, x| i * x), (0, 2, 6));
+//!   ```
+//!
+//! * **Map a reference to a tuple: [map_ref_tuple()], [map_mut_tuple()]**
+//!
+//!   `features = ["map-ref", "map-mut"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{TupleRefMapper, map_ref_tuple};
+//!   struct AsSlice;
+//!
+//!   impl<'a> TupleRefMapper<'a> for AsSlice {
+//!       type MapElem<Elem: 'a> = &'a [Elem];
+//!
+//!       fn map_elem<Elem: 'a>(&mut self, elem: &'a Elem) -> Self::MapElem<Elem> {
+//!           core::slice::from_ref(elem)
+//!       }
+//!   }
+//!
+//!   assert_eq!(
+//!       map_ref_tuple(AsSlice, &(1, "two")),
+//!       (&[1][..], &["two"][..]),
+//!   );
+//!   ```
+//!
+//! * **Map a tuple with a fallible mapper: [try_map_tuple()]**
+//!
+//!   `features = ["try-map"]`, included by default
+//!
+//!   ```
+//!   # use tupleops::{TupleTryMapper, try_map_tuple};
+//!   struct NonZero;
+//!
+//!   impl TupleTryMapper for NonZero {
+//!       type MapElem<Type> = Type;
+//!       type Error = &'static str;
+//!
+//!       fn try_map_elem<Elem>(&mut self, elem: Elem) -> Result<Elem, Self::Error> {
+//!           match core::mem::size_of::<Elem>() {
+//!               0 => Err("zero-sized element"),
+//!               _ => Ok(elem),
+//!           }
+//!       }
+//!   }
+//!
+//!   assert_eq!(try_map_tuple(NonZero, (1, "two")), Ok((1, "two")));
+//!   assert_eq!(try_map_tuple(NonZero, (1, (), 3)), Err("zero-sized element"));
+//!   ```
+//!
+//! * **Await a tuple of futures concurrently: `join_tuple()`, `try_join_tuple()`**
+//!
+//!   `features = ["async"]`, **not** included by default, because it needs Rust 1.75.
+//!   The generated poll loop runs inside an `async` block with [core::future::poll_fn()],
+//!   because the crate forbids the unsafe pin projections that a named future type would need.
+//!
+//!   ```
+//!   # #[cfg(feature = "async")] {
+//!   # use std::future::Future;
+//!   # use std::pin::pin;
+//!   # use std::sync::Arc;
+//!   # use std::task::{Context, Poll, Wake};
+//!   # use std::thread::{self, Thread};
+//!   # struct ThreadWaker(Thread);
+//!   # impl Wake for ThreadWaker {
+//!   #     fn wake(self: Arc<Self>) {
+//!   #         self.0.unpark();
+//!   #     }
+//!   # }
+//!   # fn block_on<F: Future>(fut: F) -> F::Output {
+//!   #     let mut fut = pin!(fut);
+//!   #     let waker = Arc::new(ThreadWaker(thread::current())).into();
+//!   #     let mut cx = Context::from_waker(&waker);
+//!   #     loop {
+//!   #         match fut.as_mut().poll(&mut cx) {
+//!   #             Poll::Ready(output) => return output,
+//!   #             Poll::Pending => thread::park(),
+//!   #         }
+//!   #     }
+//!   # }
+//!   # use tupleops::{join_tuple, try_join_tuple};
+//!   # block_on(async {
+//!   assert_eq!(
+//!       join_tuple((async { 1 }, async { "two" })).await,
+//!       (1, "two"),
+//!   );
+//!   assert_eq!(
+//!       try_join_tuple((async { Ok(1) }, async { Err::<&str, _>("two") })).await,
+//!       Err("two"),
+//!   );
+//!   # });
+//!   # }
+//!   ```
+//!
 //! When used in libraries, you should probably use `default-features = false`, and only opt in
 //! to the features you actually need.
 //!
@@ -205,49 +592,268 @@
 //!
 //! **Beware:** `features = ["256"]` needs about 5 GB of RAM to compile the module,
 //! so only use it if you actually need it.
+//!
+//! ## Minimum supported Rust version:
+//!
+//! The minimum supported Rust version (MSRV) is 1.65, the first stable release with
+//! [generic associated types](https://blog.rust-lang.org/2022/11/03/Rust-1.65.0.html#generic-associated-types-gats),
+//! which are needed for [map_tuple()].
+//! Raising the MSRV is considered a breaking change.
+//!
+//! Some features that are not included by default need a newer compiler:
+//!
+//! * `async` needs Rust 1.75 for
+//!   [`impl Trait` in trait method return types](https://blog.rust-lang.org/2023/12/28/Rust-1.75.0.html#async-fn-and-return-position-impl-trait-in-traits).
+//! * `core-error` needs Rust 1.81 for
+//!   [`core::error::Error`](https://blog.rust-lang.org/2024/09/05/Rust-1.81.0.html#coreerrorerror).
+//!
+//! `all-ops` and `full` include none of these features, so they build with the MSRV.
+//!
+//! The features `feature-generic_associated_types` and `feature-const_fn_trait_bound` used to
+//! enable the then unstable language features on a nightly compiler.
+//! They are no-ops now, and are only kept for backwards compatibility.
 
+#[cfg(feature = "all-ok")]
 mod tpl_all_ok;
-mod tpl_all_some;
-mod tpl_append;
-mod tpl_apply;
-mod tpl_concat;
-mod tpl_concat_many;
-mod tpl_length;
-mod tpl_map;
-mod tpl_option;
-mod tpl_prepend;
-mod tpl_ref;
-mod tpl_ref_mut;
-mod tpl_tuple;
-mod tpl_unappend;
-mod tpl_unprepend;
-
+#[cfg(feature = "all-ok")]
 pub use tpl_all_ok::*;
+
+#[cfg(feature = "all-ok-error")]
+mod tpl_all_ok_error;
+#[cfg(feature = "all-ok-error")]
+pub use tpl_all_ok_error::*;
+
+#[cfg(feature = "all-some")]
+mod tpl_all_some;
+#[cfg(feature = "all-some")]
 pub use tpl_all_some::*;
+
+#[cfg(feature = "all-some-error")]
+mod tpl_all_some_error;
+#[cfg(feature = "all-some-error")]
+pub use tpl_all_some_error::*;
+
+#[cfg(feature = "any-some")]
+mod tpl_any_some;
+#[cfg(feature = "any-some")]
+pub use tpl_any_some::*;
+
+#[cfg(feature = "append")]
+mod tpl_append;
+#[cfg(feature = "append")]
 pub use tpl_append::*;
+
+#[cfg(feature = "apply")]
+mod tpl_apply;
+#[cfg(feature = "apply")]
 pub use tpl_apply::*;
+
+#[cfg(feature = "array")]
+mod tpl_array;
+#[cfg(feature = "array")]
+pub use tpl_array::*;
+
+#[cfg(feature = "dyn")]
+mod tpl_as_dyn;
+#[cfg(feature = "dyn")]
+pub use tpl_as_dyn::*;
+
+#[cfg(feature = "collect-errors")]
+mod tpl_collect_errors;
+#[cfg(feature = "collect-errors")]
+pub use tpl_collect_errors::*;
+
+#[cfg(feature = "collect-option")]
+mod tpl_collect_option;
+#[cfg(feature = "collect-option")]
+pub use tpl_collect_option::*;
+
+#[cfg(feature = "collect-result")]
+mod tpl_collect_result;
+#[cfg(feature = "collect-result")]
+pub use tpl_collect_result::*;
+
+#[cfg(feature = "concat")]
+mod tpl_concat;
+#[cfg(feature = "concat")]
 pub use tpl_concat::*;
+
+#[cfg(feature = "concat-many")]
+mod tpl_concat_many;
+#[cfg(feature = "concat-many")]
 pub use tpl_concat_many::*;
+
+#[cfg(feature = "first-ok")]
+mod tpl_first_ok;
+#[cfg(feature = "first-ok")]
+pub use tpl_first_ok::*;
+
+#[cfg(feature = "flatten")]
+mod tpl_flatten;
+#[cfg(feature = "flatten")]
+pub use tpl_flatten::*;
+
+#[cfg(feature = "fold")]
+mod tpl_fold;
+#[cfg(feature = "fold")]
+pub use tpl_fold::*;
+
+#[cfg(feature = "for-each")]
+mod tpl_for_each;
+#[cfg(feature = "for-each")]
+pub use tpl_for_each::*;
+
+#[cfg(feature = "get")]
+mod tpl_get;
+#[cfg(feature = "get")]
+pub use tpl_get::*;
+
+#[cfg(feature = "insert-at")]
+mod tpl_insert_at;
+#[cfg(feature = "insert-at")]
+pub use tpl_insert_at::*;
+
+#[cfg(feature = "iter")]
+mod tpl_iter;
+#[cfg(feature = "iter")]
+pub use tpl_iter::*;
+
+#[cfg(feature = "async")]
+mod tpl_join;
+#[cfg(feature = "async")]
+pub use tpl_join::*;
+
+#[cfg(feature = "length")]
+mod tpl_length;
+#[cfg(feature = "length")]
 pub use tpl_length::*;
+
+#[cfg(feature = "map")]
+mod tpl_map;
+#[cfg(feature = "map")]
 pub use tpl_map::*;
+
+#[cfg(feature = "map-homogeneous")]
+mod tpl_map_homogeneous;
+#[cfg(feature = "map-homogeneous")]
+pub use tpl_map_homogeneous::*;
+
+#[cfg(feature = "map-mut")]
+mod tpl_map_mut;
+#[cfg(feature = "map-mut")]
+pub use tpl_map_mut::*;
+
+#[cfg(feature = "map-ref")]
+mod tpl_map_ref;
+#[cfg(feature = "map-ref")]
+pub use tpl_map_ref::*;
+
+#[cfg(feature = "option")]
+mod tpl_option;
+#[cfg(feature = "option")]
 pub use tpl_option::*;
+
+#[cfg(feature = "prepend")]
+mod tpl_prepend;
+#[cfg(feature = "prepend")]
 pub use tpl_prepend::*;
+
+#[cfg(feature = "ref")]
+mod tpl_ref;
+#[cfg(feature = "ref")]
 pub use tpl_ref::*;
+
+#[cfg(feature = "ref-mut")]
+mod tpl_ref_mut;
+#[cfg(feature = "ref-mut")]
 pub use tpl_ref_mut::*;
+
+#[cfg(feature = "remove-at")]
+mod tpl_remove_at;
+#[cfg(feature = "remove-at")]
+pub use tpl_remove_at::*;
+
+#[cfg(feature = "replace-at")]
+mod tpl_replace_at;
+#[cfg(feature = "replace-at")]
+pub use tpl_replace_at::*;
+
+#[cfg(feature = "result")]
+mod tpl_result;
+#[cfg(feature = "result")]
+pub use tpl_result::*;
+
+#[cfg(feature = "reverse")]
+mod tpl_reverse;
+#[cfg(feature = "reverse")]
+pub use tpl_reverse::*;
+
+#[cfg(feature = "rotate")]
+mod tpl_rotate;
+#[cfg(feature = "rotate")]
+pub use tpl_rotate::*;
+
+#[cfg(feature = "split-at")]
+mod tpl_split_at;
+#[cfg(feature = "split-at")]
+pub use tpl_split_at::*;
+
+#[cfg(feature = "swap")]
+mod tpl_swap;
+#[cfg(feature = "swap")]
+pub use tpl_swap::*;
+
+#[cfg(feature = "transpose")]
+mod tpl_transpose;
+#[cfg(feature = "transpose")]
+pub use tpl_transpose::*;
+
+#[cfg(feature = "try-all")]
+mod tpl_try_all;
+#[cfg(feature = "try-all")]
+pub use tpl_try_all::*;
+
+#[cfg(feature = "try-map")]
+mod tpl_try_map;
+#[cfg(feature = "try-map")]
+pub use tpl_try_map::*;
+
+#[cfg(feature = "tuple")]
+mod tpl_tuple;
+#[cfg(feature = "tuple")]
 pub use tpl_tuple::*;
+
+#[cfg(feature = "unappend")]
+mod tpl_unappend;
+#[cfg(feature = "unappend")]
 pub use tpl_unappend::*;
+
+#[cfg(feature = "unprepend")]
+mod tpl_unprepend;
+#[cfg(feature = "unprepend")]
 pub use tpl_unprepend::*;
 
+#[cfg(feature = "unzip")]
+mod tpl_unzip;
+#[cfg(feature = "unzip")]
+pub use tpl_unzip::*;
+
+#[cfg(feature = "zip")]
+mod tpl_zip;
+#[cfg(feature = "zip")]
+pub use tpl_zip::*;
+
+#[cfg(feature = "zip-with")]
+mod tpl_zip_with;
+#[cfg(feature = "zip-with")]
+pub use tpl_zip_with::*;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! do_impl {
-    ($feature_name:literal, $macro_name:ident, { $($body:tt)* }) => {
+    ($macro_name:ident, { $($body:tt)* }) => {
         pub use r#impl::*;
 
-        #[cfg(not(feature = $feature_name))]
-        mod r#impl {}
-
-        #[cfg(feature = $feature_name)]
         mod r#impl {
             $($body)*
 
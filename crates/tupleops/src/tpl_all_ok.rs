@@ -1,4 +1,4 @@
-crate::do_impl!("all-ok", tuple_all_ok, {
+crate::do_impl!(tuple_all_ok, {
     /// The type when a tuple of [Result]s is element-wise unwrapped.
     ///
     /// ```
@@ -0,0 +1,153 @@
+crate::do_impl!(tuple_all_ok_error, {
+    #[cfg(feature = "core-error")]
+    use core::error::Error;
+    use core::fmt;
+
+    use crate::{all_ok, AllOk, TupleAllOk};
+
+    /// Element-wise unwrap a tuple of [Result]s if all elements are good.
+    /// Return the input wrapped in an [AllOkError] otherwise.
+    ///
+    /// Unlike [all_ok()], the error can be displayed.
+    ///
+    /// ```
+    /// use tupleops::try_all_ok;
+    ///
+    /// assert_eq!(try_all_ok(("1".parse::<u8>(), "2".parse::<u16>())), Ok((1, 2)));
+    ///
+    /// let err = try_all_ok(("x".parse::<u8>(), "2".parse::<u16>())).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "not all tuple elements are Ok, failed positions: [0]",
+    /// );
+    /// ```
+    ///
+    /// With `features = ["core-error"]` the error can be used with the `?` operator:
+    ///
+    /// ```
+    /// # #[cfg(feature = "core-error")] {
+    /// use std::error::Error;
+    ///
+    /// use tupleops::try_all_ok;
+    ///
+    /// fn parse(a: &str, b: &str) -> Result<(u8, u16), Box<dyn Error>> {
+    ///     Ok(try_all_ok((a.parse::<u8>(), b.parse::<u16>()))?)
+    /// }
+    ///
+    /// assert_eq!(parse("1", "2").unwrap(), (1, 2));
+    /// assert_eq!(
+    ///     parse("x", "2").unwrap_err().source().unwrap().to_string(),
+    ///     "invalid digit found in string",
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// See also: [AllOkError], [all_ok()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "all-ok-error")))]
+    #[inline(always)]
+    pub fn try_all_ok<Tpl>(tpl: Tpl) -> Result<AllOk<Tpl>, AllOkError<Tpl>>
+    where
+        Tpl: TupleAllOk<Tpl>,
+    {
+        all_ok(tpl).map_err(AllOkError)
+    }
+
+    /// The error of [try_all_ok()], a tuple of [Result]s that are not all [Ok].
+    ///
+    /// Its [Display](fmt::Display) implementation names the positions of the [Err]s.
+    /// With `features = ["core-error"]` it implements `core::error::Error` if all error types
+    /// do, and its `source()` is the first error.
+    ///
+    /// ```
+    /// use tupleops::try_all_ok;
+    ///
+    /// let tpl: (Result<u8, ()>, Result<u16, ()>, Result<u32, ()>) = (Err(()), Ok(2), Err(()));
+    /// let err = try_all_ok(tpl).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "not all tuple elements are Ok, failed positions: [0, 2]",
+    /// );
+    /// assert_eq!(err.into_inner(), tpl);
+    /// ```
+    ///
+    /// See also: [try_all_ok()], [TupleAllOkError].
+    #[cfg_attr(docsrs, doc(cfg(feature = "all-ok-error")))]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct AllOkError<Tpl>(Tpl);
+
+    impl<Tpl> AllOkError<Tpl> {
+        /// Return a reference to the tuple of [Result]s.
+        #[inline(always)]
+        pub fn get_ref(&self) -> &Tpl {
+            &self.0
+        }
+
+        /// Return the tuple of [Result]s.
+        #[inline(always)]
+        pub fn into_inner(self) -> Tpl {
+            self.0
+        }
+    }
+
+    impl<Tpl> fmt::Display for AllOkError<Tpl>
+    where
+        Tpl: TupleAllOkError<Tpl>,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let failed = <Tpl as TupleAllOkError<Tpl>>::failed(&self.0);
+            let positions = failed
+                .as_ref()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, &failed)| failed.then_some(index));
+            f.write_str("not all tuple elements are Ok, failed positions: ")?;
+            f.debug_list().entries(positions).finish()
+        }
+    }
+
+    #[cfg(feature = "core-error")]
+    impl<Tpl> Error for AllOkError<Tpl>
+    where
+        Tpl: fmt::Debug + TupleAllOkSource<Tpl>,
+    {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            <Tpl as TupleAllOkSource<Tpl>>::source(&self.0)
+        }
+    }
+
+    /// A tuple of [Result]s that is usable with [AllOkError].
+    ///
+    /// See also: [AllOkError], [try_all_ok()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "all-ok-error")))]
+    pub trait TupleAllOkError<Tpl> {
+        #[doc(hidden)]
+        type Failed: AsRef<[bool]>;
+
+        #[doc(hidden)]
+        fn failed(tpl: &Tpl) -> Self::Failed;
+    }
+
+    /// Return the first error of a tuple of [Result]s.
+    #[cfg(feature = "core-error")]
+    #[doc(hidden)]
+    pub trait TupleAllOkSource<Tpl>: TupleAllOkError<Tpl> {
+        fn source(tpl: &Tpl) -> Option<&(dyn Error + 'static)>;
+    }
+
+    impl TupleAllOkError<()> for () {
+        type Failed = [bool; 0];
+
+        #[inline(always)]
+        fn failed((): &()) -> Self::Failed {
+            []
+        }
+    }
+
+    #[cfg(feature = "core-error")]
+    impl TupleAllOkSource<()> for () {
+        #[inline(always)]
+        fn source((): &()) -> Option<&(dyn Error + 'static)> {
+            None
+        }
+    }
+});
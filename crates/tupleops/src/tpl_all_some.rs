@@ -1,4 +1,4 @@
-crate::do_impl!("all-some", tuple_all_some, {
+crate::do_impl!(tuple_all_some, {
     /// The type when a tuple of [Option]s is element-wise unwrapped.
     ///
     /// ```
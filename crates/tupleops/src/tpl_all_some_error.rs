@@ -0,0 +1,130 @@
+crate::do_impl!(tuple_all_some_error, {
+    #[cfg(feature = "core-error")]
+    use core::error::Error;
+    use core::fmt;
+
+    use crate::{all_some, AllSome, TupleAllSome};
+
+    /// Element-wise unwrap a tuple of [Option]s if all elements are good.
+    /// Return the input wrapped in an [AllSomeError] otherwise.
+    ///
+    /// Unlike [all_some()], the error can be displayed.
+    ///
+    /// ```
+    /// use tupleops::try_all_some;
+    ///
+    /// assert_eq!(try_all_some((Some(1), Some("two"))), Ok((1, "two")));
+    ///
+    /// let err = try_all_some((Some(1), None::<&str>)).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "not all tuple elements are Some, failed positions: [1]",
+    /// );
+    /// ```
+    ///
+    /// With `features = ["core-error"]` the error can be used with the `?` operator:
+    ///
+    /// ```
+    /// # #[cfg(feature = "core-error")] {
+    /// use std::error::Error;
+    ///
+    /// use tupleops::try_all_some;
+    ///
+    /// fn lookup(a: Option<u8>, b: Option<&str>) -> Result<(u8, &str), Box<dyn Error + '_>> {
+    ///     Ok(try_all_some((a, b))?)
+    /// }
+    ///
+    /// assert_eq!(lookup(Some(1), Some("two")).unwrap(), (1, "two"));
+    /// assert_eq!(
+    ///     lookup(Some(1), None).unwrap_err().to_string(),
+    ///     "not all tuple elements are Some, failed positions: [1]",
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// See also: [AllSomeError], [all_some()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "all-some-error")))]
+    #[inline(always)]
+    pub fn try_all_some<Tpl>(tpl: Tpl) -> Result<AllSome<Tpl>, AllSomeError<Tpl>>
+    where
+        Tpl: TupleAllSome<Tpl>,
+    {
+        all_some(tpl).map_err(AllSomeError)
+    }
+
+    /// The error of [try_all_some()], a tuple of [Option]s that are not all [Some].
+    ///
+    /// Its [Display](fmt::Display) implementation names the positions of the [None]s.
+    /// With `features = ["core-error"]` it implements `core::error::Error`.
+    ///
+    /// ```
+    /// use tupleops::try_all_some;
+    ///
+    /// let tpl = (None::<u8>, Some(2), None::<u32>);
+    /// let err = try_all_some(tpl).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "not all tuple elements are Some, failed positions: [0, 2]",
+    /// );
+    /// assert_eq!(err.into_inner(), tpl);
+    /// ```
+    ///
+    /// See also: [try_all_some()], [TupleAllSomeError].
+    #[cfg_attr(docsrs, doc(cfg(feature = "all-some-error")))]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct AllSomeError<Tpl>(Tpl);
+
+    impl<Tpl> AllSomeError<Tpl> {
+        /// Return a reference to the tuple of [Option]s.
+        #[inline(always)]
+        pub fn get_ref(&self) -> &Tpl {
+            &self.0
+        }
+
+        /// Return the tuple of [Option]s.
+        #[inline(always)]
+        pub fn into_inner(self) -> Tpl {
+            self.0
+        }
+    }
+
+    impl<Tpl> fmt::Display for AllSomeError<Tpl>
+    where
+        Tpl: TupleAllSomeError<Tpl>,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let failed = <Tpl as TupleAllSomeError<Tpl>>::failed(&self.0);
+            let positions = failed
+                .as_ref()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, &failed)| failed.then_some(index));
+            f.write_str("not all tuple elements are Some, failed positions: ")?;
+            f.debug_list().entries(positions).finish()
+        }
+    }
+
+    #[cfg(feature = "core-error")]
+    impl<Tpl> Error for AllSomeError<Tpl> where Tpl: fmt::Debug + TupleAllSomeError<Tpl> {}
+
+    /// A tuple of [Option]s that is usable with [AllSomeError].
+    ///
+    /// See also: [AllSomeError], [try_all_some()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "all-some-error")))]
+    pub trait TupleAllSomeError<Tpl> {
+        #[doc(hidden)]
+        type Failed: AsRef<[bool]>;
+
+        #[doc(hidden)]
+        fn failed(tpl: &Tpl) -> Self::Failed;
+    }
+
+    impl TupleAllSomeError<()> for () {
+        type Failed = [bool; 0];
+
+        #[inline(always)]
+        fn failed((): &()) -> Self::Failed {
+            []
+        }
+    }
+});
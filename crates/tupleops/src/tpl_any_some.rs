@@ -0,0 +1,65 @@
+crate::do_impl!(tuple_any_some, {
+    /// Test if any element of a tuple of [Option]s is [Some].
+    ///
+    /// ```
+    /// use tupleops::any_some;
+    ///
+    /// assert!(any_some(&(None::<u8>, Some("two"), None::<f32>)));
+    /// assert!(!any_some(&(None::<u8>, None::<&str>)));
+    /// assert!(!any_some(&()));
+    /// ```
+    ///
+    /// See also: [count_some()], [TupleAnySome].
+    #[cfg_attr(docsrs, doc(cfg(feature = "any-some")))]
+    #[inline(always)]
+    pub fn any_some<Tpl>(tpl: &Tpl) -> bool
+    where
+        Tpl: TupleAnySome<Tpl>,
+    {
+        <Tpl as TupleAnySome<Tpl>>::any_some(tpl)
+    }
+
+    /// Count the elements of a tuple of [Option]s that are [Some].
+    ///
+    /// ```
+    /// use tupleops::count_some;
+    ///
+    /// assert_eq!(count_some(&(Some(1), None::<&str>, Some(3.0))), 2);
+    /// assert_eq!(count_some(&(None::<u8>, None::<&str>)), 0);
+    /// assert_eq!(count_some(&()), 0);
+    /// ```
+    ///
+    /// See also: [any_some()], [TupleAnySome].
+    #[cfg_attr(docsrs, doc(cfg(feature = "any-some")))]
+    #[inline(always)]
+    pub fn count_some<Tpl>(tpl: &Tpl) -> usize
+    where
+        Tpl: TupleAnySome<Tpl>,
+    {
+        <Tpl as TupleAnySome<Tpl>>::count_some(tpl)
+    }
+
+    /// A tuple that is usable with [any_some()] and [count_some()].
+    ///
+    /// See also: [any_some()], [count_some()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "any-some")))]
+    pub trait TupleAnySome<Tpl> {
+        #[doc(hidden)]
+        fn any_some(tpl: &Tpl) -> bool;
+
+        #[doc(hidden)]
+        fn count_some(tpl: &Tpl) -> usize;
+    }
+
+    impl TupleAnySome<()> for () {
+        #[inline(always)]
+        fn any_some((): &()) -> bool {
+            false
+        }
+
+        #[inline(always)]
+        fn count_some((): &()) -> usize {
+            0
+        }
+    }
+});
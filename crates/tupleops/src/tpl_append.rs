@@ -1,4 +1,4 @@
-crate::do_impl!("append", tuple_append, {
+crate::do_impl!(tuple_append, {
     /// The resulting type when an element is appended to an initial tuple.
     ///
     /// ```
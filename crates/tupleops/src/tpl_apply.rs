@@ -1,4 +1,4 @@
-crate::do_impl!("apply", tuple_apply, {
+crate::do_impl!(tuple_apply, {
     /// The resulting type when F is called with Tpl's elements.
     ///
     /// See also: [apply()], [TupleApply].
@@ -0,0 +1,177 @@
+crate::do_impl!(tuple_array, {
+    #[cfg(feature = "ref-mut")]
+    use crate::{ref_mut_tuple, RefMutTuple, TupleRefMut};
+    #[cfg(feature = "ref")]
+    use crate::{ref_tuple, RefTuple, TupleRef};
+
+    /// The array type of a non-empty tuple whose elements all have the same type.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::TupleArray;
+    ///
+    /// assert_same_types!(
+    ///     TupleArray<(u8, u8, u8)>,
+    ///     [u8; 3],
+    /// );
+    /// ```
+    ///
+    /// See also: [tuple_to_array()], [TupleToArray].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    pub type TupleArray<Tpl> = <Tpl as TupleToArray<Tpl>>::Type;
+
+    /// The tuple type of an array.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::ArrayTuple;
+    ///
+    /// assert_same_types!(
+    ///     ArrayTuple<[u8; 3]>,
+    ///     (u8, u8, u8),
+    /// );
+    /// ```
+    ///
+    /// See also: [array_to_tuple()], [ArrayToTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    pub type ArrayTuple<Arr> = <Arr as ArrayToTuple<Arr>>::Type;
+
+    /// Turn a non-empty tuple whose elements all have the same type into an array.
+    ///
+    /// ```
+    /// use tupleops::tuple_to_array;
+    ///
+    /// assert_eq!(
+    ///     tuple_to_array((1, 2, 3)),
+    ///     [1, 2, 3],
+    /// );
+    /// ```
+    ///
+    /// See also: [TupleArray], [TupleToArray], [array_to_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    #[inline(always)]
+    pub fn tuple_to_array<Tpl>(tpl: Tpl) -> TupleArray<Tpl>
+    where
+        Tpl: TupleToArray<Tpl>,
+    {
+        <Tpl as TupleToArray<Tpl>>::tuple_to_array(tpl)
+    }
+
+    /// Turn an array into a tuple.
+    ///
+    /// ```
+    /// use tupleops::array_to_tuple;
+    ///
+    /// assert_eq!(
+    ///     array_to_tuple([1, 2, 3]),
+    ///     (1, 2, 3),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     array_to_tuple::<[u8; 0]>([]),
+    ///     (),
+    /// );
+    /// ```
+    ///
+    /// See also: [ArrayTuple], [ArrayToTuple], [tuple_to_array()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    #[inline(always)]
+    pub fn array_to_tuple<Arr>(arr: Arr) -> ArrayTuple<Arr>
+    where
+        Arr: ArrayToTuple<Arr>,
+    {
+        <Arr as ArrayToTuple<Arr>>::array_to_tuple(arr)
+    }
+
+    /// Turn a reference to a non-empty tuple whose elements all have the same type into an array
+    /// of references.
+    ///
+    /// ```
+    /// use tupleops::ref_tuple_to_array;
+    ///
+    /// let tpl = (1, 2, 3);
+    /// let sum: i32 = ref_tuple_to_array(&tpl).iter().copied().sum();
+    /// assert_eq!(sum, 6);
+    /// ```
+    ///
+    /// See also: [tuple_to_array()], [ref_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref")))]
+    #[cfg(feature = "ref")]
+    #[inline(always)]
+    pub fn ref_tuple_to_array<'a, Tpl>(tpl: &'a Tpl) -> TupleArray<RefTuple<'a, Tpl>>
+    where
+        &'a Tpl: TupleRef<'a, Tpl>,
+        RefTuple<'a, Tpl>: TupleToArray<RefTuple<'a, Tpl>>,
+    {
+        tuple_to_array(ref_tuple(tpl))
+    }
+
+    /// Turn a reference to a mutable, non-empty tuple whose elements all have the same type into
+    /// an array of mutable references.
+    ///
+    /// ```
+    /// use tupleops::ref_mut_tuple_to_array;
+    ///
+    /// let mut tpl = (1, 2, 3);
+    /// for elem in ref_mut_tuple_to_array(&mut tpl) {
+    ///     *elem *= 10;
+    /// }
+    /// assert_eq!(tpl, (10, 20, 30));
+    /// ```
+    ///
+    /// See also: [tuple_to_array()], [ref_mut_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref-mut")))]
+    #[cfg(feature = "ref-mut")]
+    #[inline(always)]
+    pub fn ref_mut_tuple_to_array<'a, Tpl>(tpl: &'a mut Tpl) -> TupleArray<RefMutTuple<'a, Tpl>>
+    where
+        &'a mut Tpl: TupleRefMut<'a, Tpl>,
+        RefMutTuple<'a, Tpl>: TupleToArray<RefMutTuple<'a, Tpl>>,
+    {
+        tuple_to_array(ref_mut_tuple(tpl))
+    }
+
+    /// A non-empty tuple whose elements all have the same type, that is usable with
+    /// [tuple_to_array()].
+    ///
+    /// Tuples with elements of different types cannot be turned into an array:
+    ///
+    /// ```compile_fail
+    /// use tupleops::tuple_to_array;
+    ///
+    /// tuple_to_array((1_u8, 2_u16));
+    /// ```
+    ///
+    /// See also: [tuple_to_array()], [TupleArray].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    pub trait TupleToArray<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn tuple_to_array(tpl: Tpl) -> Self::Type;
+    }
+
+    /// An array that is usable with [array_to_tuple()].
+    ///
+    /// See also: [array_to_tuple()], [ArrayTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "array")))]
+    pub trait ArrayToTuple<Arr> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn array_to_tuple(arr: Arr) -> Self::Type;
+    }
+
+    impl<T> ArrayToTuple<[T; 0]> for [T; 0] {
+        type Type = ();
+
+        #[inline(always)]
+        fn array_to_tuple(arr: [T; 0]) -> Self::Type {
+            let [] = arr;
+        }
+    }
+});
@@ -0,0 +1,254 @@
+crate::do_impl!(tuple_as_dyn, {
+    use core::any::Any;
+    use core::fmt::{Debug, Display};
+
+    /// The iterator type over references to the elements of a tuple as trait objects.
+    ///
+    /// See also: [iter_dyn()], [TupleAsDyn].
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    pub type IterDyn<'a, Dyn, Tpl> = <Tpl as TupleAsDyn<'a, Dyn>>::Ref;
+
+    /// The iterator type over mutable references to the elements of a tuple as trait objects.
+    ///
+    /// See also: [iter_dyn_mut()], [TupleAsDyn].
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    pub type IterDynMut<'a, Dyn, Tpl> = <Tpl as TupleAsDyn<'a, Dyn>>::Mut;
+
+    /// Iterate over references to the elements of a tuple as trait objects `Dyn`.
+    ///
+    /// The elements can have different types. `Dyn` must implement [CoerceDyn] for every
+    /// element type, which is the case for `dyn Any` (also with `+ Send` and `+ Send + Sync`),
+    /// `dyn Debug`, `dyn Display`, and traits that were registered with
+    /// [impl_coerce_dyn!](crate::impl_coerce_dyn).
+    /// No allocation is needed.
+    ///
+    /// ```
+    /// use core::fmt::{Display, Write};
+    ///
+    /// use tupleops::iter_dyn;
+    ///
+    /// let mut result = String::new();
+    /// for elem in iter_dyn::<dyn Display, _>(&(1, "two", 3.5)) {
+    ///     write!(result, "<{}>", elem).unwrap();
+    /// }
+    /// assert_eq!(result, "<1><two><3.5>");
+    /// ```
+    ///
+    /// ```
+    /// use core::any::Any;
+    ///
+    /// use tupleops::iter_dyn;
+    ///
+    /// let tpl = (1_u8, "two", 3_u8);
+    /// let bytes: Vec<u8> = iter_dyn::<dyn Any, _>(&tpl)
+    ///     .filter_map(|elem| elem.downcast_ref::<u8>())
+    ///     .copied()
+    ///     .collect();
+    /// assert_eq!(bytes, [1, 3]);
+    /// ```
+    ///
+    /// See also: [IterDyn], [TupleAsDyn], [TupleIterDyn], [iter_dyn_mut()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    #[inline(always)]
+    pub fn iter_dyn<'a, Dyn, Tpl>(tpl: &'a Tpl) -> IterDyn<'a, Dyn, Tpl>
+    where
+        Dyn: ?Sized + 'a,
+        Tpl: TupleAsDyn<'a, Dyn>,
+    {
+        <Tpl as TupleAsDyn<'a, Dyn>>::iter_dyn(tpl)
+    }
+
+    /// Iterate over mutable references to the elements of a tuple as trait objects `Dyn`.
+    ///
+    /// ```
+    /// use core::any::Any;
+    ///
+    /// use tupleops::iter_dyn_mut;
+    ///
+    /// let mut tpl = (1_u8, "two", 3_u8);
+    /// for elem in iter_dyn_mut::<dyn Any, _>(&mut tpl) {
+    ///     if let Some(elem) = elem.downcast_mut::<u8>() {
+    ///         *elem *= 10;
+    ///     }
+    /// }
+    /// assert_eq!(tpl, (10, "two", 30));
+    /// ```
+    ///
+    /// See also: [IterDynMut], [TupleAsDyn], [TupleIterDyn], [iter_dyn()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    #[inline(always)]
+    pub fn iter_dyn_mut<'a, Dyn, Tpl>(tpl: &'a mut Tpl) -> IterDynMut<'a, Dyn, Tpl>
+    where
+        Dyn: ?Sized + 'a,
+        Tpl: TupleAsDyn<'a, Dyn>,
+    {
+        <Tpl as TupleAsDyn<'a, Dyn>>::iter_dyn_mut(tpl)
+    }
+
+    /// A tuple whose elements can be seen as trait objects `Dyn`, usable with [iter_dyn()] and
+    /// [iter_dyn_mut()].
+    ///
+    /// Every element type must implement the trait:
+    ///
+    /// ```compile_fail
+    /// use core::fmt::Display;
+    ///
+    /// use tupleops::iter_dyn;
+    ///
+    /// struct NotDisplay;
+    ///
+    /// iter_dyn::<dyn Display, _>(&(1, NotDisplay));
+    /// ```
+    ///
+    /// See also: [iter_dyn()], [iter_dyn_mut()], [CoerceDyn].
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    pub trait TupleAsDyn<'a, Dyn: ?Sized + 'a> {
+        #[doc(hidden)]
+        type Ref: ExactSizeIterator<Item = &'a Dyn>;
+
+        #[doc(hidden)]
+        type Mut: ExactSizeIterator<Item = &'a mut Dyn>;
+
+        #[doc(hidden)]
+        fn iter_dyn(tpl: &'a Self) -> Self::Ref;
+
+        #[doc(hidden)]
+        fn iter_dyn_mut(tpl: &'a mut Self) -> Self::Mut;
+    }
+
+    /// Method syntax for [iter_dyn()] and [iter_dyn_mut()].
+    ///
+    /// ```
+    /// use core::fmt::{Display, Write};
+    ///
+    /// use tupleops::TupleIterDyn;
+    ///
+    /// let mut result = String::new();
+    /// for elem in (1, "two", 3.5).iter_dyn::<dyn Display>() {
+    ///     write!(result, "<{}>", elem).unwrap();
+    /// }
+    /// assert_eq!(result, "<1><two><3.5>");
+    /// ```
+    ///
+    /// See also: [iter_dyn()], [iter_dyn_mut()], [TupleAsDyn].
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    pub trait TupleIterDyn {
+        /// Iterate over references to the elements of a tuple as trait objects `Dyn`.
+        ///
+        /// See also: [iter_dyn()].
+        #[inline(always)]
+        fn iter_dyn<'a, Dyn>(&'a self) -> IterDyn<'a, Dyn, Self>
+        where
+            Dyn: ?Sized + 'a,
+            Self: TupleAsDyn<'a, Dyn>,
+        {
+            <Self as TupleAsDyn<'a, Dyn>>::iter_dyn(self)
+        }
+
+        /// Iterate over mutable references to the elements of a tuple as trait objects `Dyn`.
+        ///
+        /// See also: [iter_dyn_mut()].
+        #[inline(always)]
+        fn iter_dyn_mut<'a, Dyn>(&'a mut self) -> IterDynMut<'a, Dyn, Self>
+        where
+            Dyn: ?Sized + 'a,
+            Self: TupleAsDyn<'a, Dyn>,
+        {
+            <Self as TupleAsDyn<'a, Dyn>>::iter_dyn_mut(self)
+        }
+    }
+
+    impl<Tpl> TupleIterDyn for Tpl {}
+
+    /// A trait object type `Self` that a reference to `T` can be turned into.
+    ///
+    /// Use [impl_coerce_dyn!](crate::impl_coerce_dyn) to implement this trait for your own
+    /// traits.
+    ///
+    /// See also: [iter_dyn()], [iter_dyn_mut()], [TupleAsDyn].
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    pub trait CoerceDyn<T> {
+        /// Turn a reference into a reference to a trait object.
+        fn coerce_ref(elem: &T) -> &Self;
+
+        /// Turn a mutable reference into a mutable reference to a trait object.
+        fn coerce_mut(elem: &mut T) -> &mut Self;
+    }
+
+    /// Implement [CoerceDyn] for `dyn Trait`, so it can be used with [iter_dyn()] and
+    /// [iter_dyn_mut()].
+    ///
+    /// Auto trait bounds like `Send` and `Sync` can be added with `+`, e.g. `Shape + Send`.
+    ///
+    /// ```
+    /// use tupleops::{impl_coerce_dyn, iter_dyn};
+    ///
+    /// trait Shape {
+    ///     fn area(&self) -> f64;
+    /// }
+    ///
+    /// struct Square(f64);
+    ///
+    /// impl Shape for Square {
+    ///     fn area(&self) -> f64 {
+    ///         self.0 * self.0
+    ///     }
+    /// }
+    ///
+    /// struct Rectangle(f64, f64);
+    ///
+    /// impl Shape for Rectangle {
+    ///     fn area(&self) -> f64 {
+    ///         self.0 * self.1
+    ///     }
+    /// }
+    ///
+    /// impl_coerce_dyn!(Shape, Shape + Send + Sync);
+    ///
+    /// let shapes = (Square(2.0), Rectangle(2.0, 3.0));
+    /// let area: f64 = iter_dyn::<dyn Shape, _>(&shapes).map(|s| s.area()).sum();
+    /// assert_eq!(area, 10.0);
+    ///
+    /// let shapes: Vec<&(dyn Shape + Send + Sync)> =
+    ///     iter_dyn::<dyn Shape + Send + Sync, _>(&shapes).collect();
+    /// assert_eq!(shapes.len(), 2);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "dyn")))]
+    #[macro_export]
+    macro_rules! impl_coerce_dyn {
+        ($($($trait:ident)::+ $(+ $bound:ident)*),+ $(,)?) => {
+            $(
+                impl<'a, T: $($trait)::+ $(+ $bound)* + 'a> $crate::CoerceDyn<T>
+                    for dyn $($trait)::+ $(+ $bound)* + 'a
+                {
+                    #[inline(always)]
+                    fn coerce_ref(elem: &T) -> &Self {
+                        elem
+                    }
+
+                    #[inline(always)]
+                    fn coerce_mut(elem: &mut T) -> &mut Self {
+                        elem
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_coerce_dyn!(Any, Any + Send, Any + Send + Sync, Debug, Display);
+
+    impl<'a, Dyn: ?Sized + 'a> TupleAsDyn<'a, Dyn> for () {
+        type Ref = core::array::IntoIter<&'a Dyn, 0>;
+        type Mut = core::array::IntoIter<&'a mut Dyn, 0>;
+
+        #[inline(always)]
+        fn iter_dyn((): &'a ()) -> Self::Ref {
+            IntoIterator::into_iter([])
+        }
+
+        #[inline(always)]
+        fn iter_dyn_mut((): &'a mut ()) -> Self::Mut {
+            IntoIterator::into_iter([])
+        }
+    }
+});
@@ -0,0 +1,132 @@
+crate::do_impl!(tuple_collect_errors, {
+    use core::{
+        array::IntoIter,
+        iter::{Enumerate, FusedIterator},
+    };
+
+    use crate::{tuple_to_array, AllOk, TupleAllOk, TupleToArray};
+
+    /// The type of the errors of a tuple of [Result]s, a tuple of [Option]s.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::ErrorsTuple;
+    ///
+    /// assert_same_types!(
+    ///     ErrorsTuple<(Result<u8, i8>, Result<u16, i16>)>,
+    ///     (Option<i8>, Option<i16>),
+    /// );
+    /// ```
+    ///
+    /// See also: [collect_errors()], [TupleCollectErrors].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-errors")))]
+    pub type ErrorsTuple<Tpl> = <Tpl as TupleCollectErrors<Tpl>>::Errors;
+
+    /// Element-wise unwrap a tuple of [Result]s if all elements are good.
+    /// Return all errors otherwise.
+    ///
+    /// The error is a tuple that contains [Some] error at every position that was an [Err],
+    /// and [None] at every position that was [Ok].
+    ///
+    /// ```
+    /// use tupleops::collect_errors;
+    ///
+    /// let good: (Result<u8, &str>, Result<&str, u32>) = (Ok(1), Ok("two"));
+    /// assert_eq!(
+    ///     collect_errors(good),
+    ///     Ok((1, "two")),
+    /// );
+    ///
+    /// let bad: (Result<u8, &str>, Result<&str, u32>, Result<f32, ()>) =
+    ///     (Err("one"), Ok("two"), Err(()));
+    /// assert_eq!(
+    ///     collect_errors(bad),
+    ///     Err((Some("one"), None, Some(()))),
+    /// );
+    /// ```
+    ///
+    /// See also: [ErrorsTuple], [TupleCollectErrors], [all_ok()](crate::all_ok).
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-errors")))]
+    #[inline(always)]
+    pub fn collect_errors<Tpl>(tpl: Tpl) -> Result<AllOk<Tpl>, ErrorsTuple<Tpl>>
+    where
+        Tpl: TupleCollectErrors<Tpl>,
+    {
+        <Tpl as TupleCollectErrors<Tpl>>::collect_errors(tpl)
+    }
+
+    /// Element-wise unwrap a non-empty tuple of [Result]s with the same error type if all
+    /// elements are good.
+    /// Return an iterator over the indices and errors of the bad elements otherwise.
+    ///
+    /// ```
+    /// use tupleops::collect_errors_indexed;
+    ///
+    /// let bad: (Result<u8, &str>, Result<&str, &str>, Result<f32, &str>) =
+    ///     (Err("one"), Ok("two"), Err("three"));
+    /// let errors: Vec<_> = collect_errors_indexed(bad).unwrap_err().collect();
+    /// assert_eq!(errors, [(0, "one"), (2, "three")]);
+    /// ```
+    ///
+    /// See also: [collect_errors()], [IndexedErrors].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-errors")))]
+    #[inline(always)]
+    pub fn collect_errors_indexed<Tpl, E, const N: usize>(
+        tpl: Tpl,
+    ) -> Result<AllOk<Tpl>, IndexedErrors<E, N>>
+    where
+        Tpl: TupleCollectErrors<Tpl>,
+        ErrorsTuple<Tpl>: TupleToArray<ErrorsTuple<Tpl>, Type = [Option<E>; N]>,
+    {
+        collect_errors(tpl).map_err(|errors| IndexedErrors {
+            iter: IntoIterator::into_iter(tuple_to_array(errors)).enumerate(),
+        })
+    }
+
+    /// An iterator over the indices and errors of a tuple of [Result]s.
+    ///
+    /// See also: [collect_errors_indexed()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-errors")))]
+    #[derive(Debug, Clone)]
+    pub struct IndexedErrors<E, const N: usize> {
+        iter: Enumerate<IntoIter<Option<E>, N>>,
+    }
+
+    impl<E, const N: usize> Iterator for IndexedErrors<E, N> {
+        type Item = (usize, E);
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.iter.find_map(|(index, error)| Some((index, error?)))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, self.iter.size_hint().1)
+        }
+    }
+
+    impl<E, const N: usize> FusedIterator for IndexedErrors<E, N> {}
+
+    /// A tuple that is usable with [collect_errors()].
+    ///
+    /// See also: [collect_errors()], [ErrorsTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-errors")))]
+    pub trait TupleCollectErrors<Tpl>: TupleAllOk<Tpl> {
+        #[doc(hidden)]
+        type Errors;
+
+        #[doc(hidden)]
+        fn collect_errors(tpl: Tpl) -> Result<<Self as TupleAllOk<Tpl>>::Type, Self::Errors>;
+    }
+
+    impl TupleCollectErrors<()> for () {
+        type Errors = ();
+
+        #[inline(always)]
+        fn collect_errors(tpl: ()) -> Result<(), Self::Errors> {
+            let () = tpl;
+            Ok(())
+        }
+    }
+});
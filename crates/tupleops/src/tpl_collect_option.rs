@@ -0,0 +1,73 @@
+crate::do_impl!(tuple_collect_option, {
+    /// The type when a tuple of [Option]s is turned into an [Option] of a tuple.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::CollectOption;
+    ///
+    /// assert_same_types!(
+    ///     CollectOption<(Option<u8>, Option<u16>, Option<u32>)>,
+    ///     Option<(u8, u16, u32)>,
+    /// );
+    /// ```
+    ///
+    /// See also: [collect_option()], [TupleCollectOption].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-option")))]
+    pub type CollectOption<Tpl> = Option<<Tpl as TupleCollectOption<Tpl>>::Type>;
+
+    /// Turn a tuple of [Option]s into an [Option] of a tuple.
+    ///
+    /// Return [None] if any element is [None].
+    /// Unlike `all_some()`, the input is dropped in this case.
+    ///
+    /// ```
+    /// use tupleops::collect_option;
+    ///
+    /// assert_eq!(
+    ///     collect_option((Some(1), Some("two"), Some(3.0))),
+    ///     Some((1, "two", 3.0)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     collect_option((Some(1), Option::<&str>::None, Some(3.0))),
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     collect_option(()),
+    ///     Some(()),
+    /// );
+    /// ```
+    ///
+    /// See also: [CollectOption], [TupleCollectOption].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-option")))]
+    #[inline(always)]
+    pub fn collect_option<Tpl>(tpl: Tpl) -> CollectOption<Tpl>
+    where
+        Tpl: TupleCollectOption<Tpl>,
+    {
+        <Tpl as TupleCollectOption<Tpl>>::collect_option(tpl)
+    }
+
+    /// A tuple that is usable with [collect_option()].
+    ///
+    /// See also: [collect_option()], [CollectOption].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-option")))]
+    pub trait TupleCollectOption<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn collect_option(tpl: Tpl) -> Option<Self::Type>;
+    }
+
+    impl TupleCollectOption<()> for () {
+        type Type = ();
+
+        #[inline(always)]
+        fn collect_option(tpl: ()) -> Option<Self::Type> {
+            let () = tpl;
+            Some(())
+        }
+    }
+});
@@ -0,0 +1,75 @@
+crate::do_impl!(tuple_collect_result, {
+    /// The type when a non-empty tuple of [Result]s with the same error type is turned into a
+    /// [Result] of a tuple.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::CollectResult;
+    ///
+    /// assert_same_types!(
+    ///     CollectResult<(Result<u8, ()>, Result<u16, ()>)>,
+    ///     Result<(u8, u16), ()>,
+    /// );
+    /// ```
+    ///
+    /// See also: [collect_result()], [TupleCollectResult].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-result")))]
+    pub type CollectResult<Tpl> =
+        Result<<Tpl as TupleCollectResult<Tpl>>::Type, <Tpl as TupleCollectResult<Tpl>>::Error>;
+
+    /// Turn a non-empty tuple of [Result]s with the same error type into a [Result] of a tuple.
+    ///
+    /// Return the first error if any element is an [Err].
+    /// Unlike `all_ok()`, the remaining input is dropped in this case.
+    ///
+    /// ```
+    /// use tupleops::collect_result;
+    ///
+    /// let good: (Result<u8, &str>, Result<&str, &str>) = (Ok(1), Ok("two"));
+    /// assert_eq!(
+    ///     collect_result(good),
+    ///     Ok((1, "two")),
+    /// );
+    ///
+    /// let bad: (Result<u8, &str>, Result<&str, &str>, Result<f32, &str>) =
+    ///     (Ok(1), Err("first"), Err("second"));
+    /// assert_eq!(
+    ///     collect_result(bad),
+    ///     Err("first"),
+    /// );
+    /// ```
+    ///
+    /// See also: [CollectResult], [TupleCollectResult].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-result")))]
+    #[inline(always)]
+    pub fn collect_result<Tpl>(tpl: Tpl) -> CollectResult<Tpl>
+    where
+        Tpl: TupleCollectResult<Tpl>,
+    {
+        <Tpl as TupleCollectResult<Tpl>>::collect_result(tpl)
+    }
+
+    /// A tuple that is usable with [collect_result()].
+    ///
+    /// Tuples with different error types cannot be collected:
+    ///
+    /// ```compile_fail
+    /// use tupleops::collect_result;
+    ///
+    /// let tpl: (Result<u8, u8>, Result<u8, u16>) = (Ok(1), Ok(2));
+    /// collect_result(tpl);
+    /// ```
+    ///
+    /// See also: [collect_result()], [CollectResult].
+    #[cfg_attr(docsrs, doc(cfg(feature = "collect-result")))]
+    pub trait TupleCollectResult<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        type Error;
+
+        #[doc(hidden)]
+        fn collect_result(tpl: Tpl) -> Result<Self::Type, Self::Error>;
+    }
+});
@@ -1,4 +1,4 @@
-crate::do_impl!("concat", tuple_concat, {
+crate::do_impl!(tuple_concat, {
     use crate::{prepend, Prepend, TuplePrepend};
 
     /// The resulting type when two tuples are concatenated.
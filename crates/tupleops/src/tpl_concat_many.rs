@@ -1,4 +1,4 @@
-crate::do_impl!("concat-many", tuple_concat_many, {
+crate::do_impl!(tuple_concat_many, {
     use crate::{concat_tuples, ConcatTuples, TupleConcat};
 
     /// The resulting type when two or more tuples are concatenated.
@@ -0,0 +1,191 @@
+crate::do_impl!(tuple_first_ok, {
+    use core::convert::Infallible;
+
+    /// The type of the value of the first [Ok] in a non-empty tuple of [Result]s whose
+    /// [Ok] types are the same.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::FirstOk;
+    ///
+    /// assert_same_types!(
+    ///     FirstOk<(Result<u8, i8>, Result<u8, i16>)>,
+    ///     u8,
+    /// );
+    /// ```
+    ///
+    /// See also: [first_ok()], [TupleFirstOk].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    pub type FirstOk<Tpl> = <Tpl as TupleFirstOk<Tpl>>::Type;
+
+    /// The type of the value of the first [Ok] in a tuple of [Result]s, a nested [Either].
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::{Either, FirstOkEither};
+    ///
+    /// assert_same_types!(
+    ///     FirstOkEither<(Result<u8, ()>, Result<u16, ()>, Result<u32, ()>)>,
+    ///     Either<u8, Either<u16, u32>>,
+    /// );
+    ///
+    /// assert_same_types!(
+    ///     FirstOkEither<(Result<u8, ()>,)>,
+    ///     u8,
+    /// );
+    /// ```
+    ///
+    /// See also: [first_ok_either()], [TupleFirstOkEither].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    pub type FirstOkEither<Tpl> = <Tpl as TupleFirstOkEither<Tpl>>::Type;
+
+    /// The type of the errors if no element of a tuple of [Result]s is [Ok].
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::FirstOkErrors;
+    ///
+    /// assert_same_types!(
+    ///     FirstOkErrors<(Result<u8, i8>, Result<u16, i16>)>,
+    ///     (i8, i16),
+    /// );
+    /// ```
+    ///
+    /// See also: [first_ok()], [first_ok_either()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    pub type FirstOkErrors<Tpl> = <Tpl as TupleFirstOkEither<Tpl>>::Errors;
+
+    /// Return the index and the value of the first [Ok] in a non-empty tuple of [Result]s whose
+    /// [Ok] types are the same.
+    /// Return all errors otherwise.
+    ///
+    /// ```
+    /// use tupleops::first_ok;
+    ///
+    /// let tpl: (Result<u8, &str>, Result<u8, ()>, Result<u8, f32>) = (Err("one"), Ok(2), Ok(3));
+    /// assert_eq!(
+    ///     first_ok(tpl),
+    ///     Ok((1, 2)),
+    /// );
+    ///
+    /// let tpl: (Result<u8, &str>, Result<u8, ()>) = (Err("one"), Err(()));
+    /// assert_eq!(
+    ///     first_ok(tpl),
+    ///     Err(("one", ())),
+    /// );
+    /// ```
+    ///
+    /// See also: [FirstOk], [TupleFirstOk], [first_ok_either()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    #[inline(always)]
+    pub fn first_ok<Tpl>(
+        tpl: Tpl,
+    ) -> Result<(usize, FirstOk<Tpl>), <Tpl as TupleFirstOk<Tpl>>::Errors>
+    where
+        Tpl: TupleFirstOk<Tpl>,
+    {
+        <Tpl as TupleFirstOk<Tpl>>::first_ok(tpl)
+    }
+
+    /// Return the value of the first [Ok] in a tuple of [Result]s as a nested [Either].
+    /// Return all errors otherwise.
+    ///
+    /// The value of the element at index N is wrapped N times in [Either::Right], and then in
+    /// [Either::Left], unless it is the last element.
+    ///
+    /// ```
+    /// use tupleops::{first_ok_either, Either};
+    ///
+    /// type Tpl<'a> = (Result<u8, ()>, Result<&'a str, ()>, Result<f32, ()>);
+    ///
+    /// let tpl: Tpl = (Err(()), Ok("two"), Ok(3.0));
+    /// assert_eq!(
+    ///     first_ok_either(tpl),
+    ///     Ok(Either::Right(Either::Left("two"))),
+    /// );
+    ///
+    /// let tpl: Tpl = (Err(()), Err(()), Ok(3.0));
+    /// assert_eq!(
+    ///     first_ok_either(tpl),
+    ///     Ok(Either::Right(Either::Right(3.0))),
+    /// );
+    ///
+    /// let tpl: (Result<u8, &str>, Result<&str, ()>) = (Err("one"), Err(()));
+    /// assert_eq!(
+    ///     first_ok_either(tpl),
+    ///     Err(("one", ())),
+    /// );
+    /// ```
+    ///
+    /// See also: [FirstOkEither], [TupleFirstOkEither], [first_ok()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    #[inline(always)]
+    pub fn first_ok_either<Tpl>(tpl: Tpl) -> Result<FirstOkEither<Tpl>, FirstOkErrors<Tpl>>
+    where
+        Tpl: TupleFirstOkEither<Tpl>,
+    {
+        <Tpl as TupleFirstOkEither<Tpl>>::first_ok_either(tpl)
+    }
+
+    /// A value that is one of two types.
+    ///
+    /// See also: [first_ok_either()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Either<L, R> {
+        /// The value is of the left type.
+        Left(L),
+        /// The value is of the right type.
+        Right(R),
+    }
+
+    /// A non-empty tuple of [Result]s whose [Ok] types are the same, that is usable with
+    /// [first_ok()].
+    ///
+    /// Tuples with different [Ok] types need [first_ok_either()]:
+    ///
+    /// ```compile_fail
+    /// use tupleops::first_ok;
+    ///
+    /// let tpl: (Result<u8, ()>, Result<u16, ()>) = (Ok(1), Ok(2));
+    /// first_ok(tpl);
+    /// ```
+    ///
+    /// See also: [first_ok()], [FirstOk].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    pub trait TupleFirstOk<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        type Errors;
+
+        #[doc(hidden)]
+        fn first_ok(tpl: Tpl) -> Result<(usize, Self::Type), Self::Errors>;
+    }
+
+    /// A tuple of [Result]s that is usable with [first_ok_either()].
+    ///
+    /// See also: [first_ok_either()], [FirstOkEither], [FirstOkErrors].
+    #[cfg_attr(docsrs, doc(cfg(feature = "first-ok")))]
+    pub trait TupleFirstOkEither<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        type Errors;
+
+        #[doc(hidden)]
+        fn first_ok_either(tpl: Tpl) -> Result<Self::Type, Self::Errors>;
+    }
+
+    impl TupleFirstOkEither<()> for () {
+        type Type = Infallible;
+        type Errors = ();
+
+        #[inline(always)]
+        fn first_ok_either((): ()) -> Result<Self::Type, Self::Errors> {
+            Err(())
+        }
+    }
+});
@@ -0,0 +1,191 @@
+crate::do_impl!(tuple_flatten, {
+    use core::marker::PhantomData;
+
+    use crate::{concat_many, ConcatMany, TupleConcatMany};
+
+    /// The resulting type when a tuple is recursively flattened.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::Flatten;
+    ///
+    /// assert_same_types!(
+    ///     Flatten<(u8, (u16, (u32, u64)), ())>,
+    ///     (u8, u16, u32, u64),
+    /// );
+    /// ```
+    ///
+    /// See also: [flatten_tuple()], [TupleFlatten].
+    #[cfg_attr(docsrs, doc(cfg(feature = "flatten")))]
+    pub type Flatten<Tpl> = <Tpl as TupleFlatten<Tpl>>::Type;
+
+    /// Recursively flatten a tuple of tuples.
+    ///
+    /// Every element that is a tuple is flattened.
+    /// Every element that is a [FlattenLeaf] is kept as is.
+    /// Tuples that should be kept as values can be wrapped in a [Leaf].
+    ///
+    /// ```
+    /// use tupleops::{flatten_tuple, FlattenLeaf, Leaf};
+    ///
+    /// assert_eq!(
+    ///     flatten_tuple((1, (2, (3, 4)), 5)),
+    ///     (1, 2, 3, 4, 5),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     flatten_tuple(((), ((),), 1)),
+    ///     (1,),
+    /// );
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Point(i32, i32);
+    ///
+    /// impl FlattenLeaf for Point {}
+    ///
+    /// assert_eq!(
+    ///     flatten_tuple((Point(1, 2), (Leaf((3, 4)), "five"))),
+    ///     (Point(1, 2), (3, 4), "five"),
+    /// );
+    /// ```
+    ///
+    /// See also: [Flatten], [TupleFlatten].
+    #[cfg_attr(docsrs, doc(cfg(feature = "flatten")))]
+    #[inline(always)]
+    pub fn flatten_tuple<Tpl>(tpl: Tpl) -> Flatten<Tpl>
+    where
+        Tpl: TupleFlatten<Tpl>,
+    {
+        <Tpl as TupleFlatten<Tpl>>::flatten_tuple(tpl)
+    }
+
+    /// A tuple that is usable with [flatten_tuple()].
+    ///
+    /// Types that are neither tuples nor a [FlattenLeaf] cannot be flattened:
+    ///
+    /// ```compile_fail
+    /// use tupleops::flatten_tuple;
+    ///
+    /// struct Point(i32, i32);
+    ///
+    /// flatten_tuple((Point(1, 2), 3));
+    /// ```
+    ///
+    /// See also: [flatten_tuple()], [Flatten].
+    #[cfg_attr(docsrs, doc(cfg(feature = "flatten")))]
+    pub trait TupleFlatten<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn flatten_tuple(tpl: Tpl) -> Self::Type;
+    }
+
+    /// A type that is kept as a single element by [flatten_tuple()].
+    ///
+    /// This marker is implemented for primitive types, references, arrays, and most types of
+    /// [core]. Implement it for your own types to use them with [flatten_tuple()].
+    /// Types of other crates, e.g. `String`, cannot implement it because of the orphan rule,
+    /// so wrap them in a [Leaf] instead.
+    ///
+    /// Without specialization a blanket implementation for every type that is not a tuple would
+    /// overlap with the implementations for tuples, so the leaves have to be marked.
+    ///
+    /// See also: [flatten_tuple()], [Leaf].
+    #[cfg_attr(docsrs, doc(cfg(feature = "flatten")))]
+    pub trait FlattenLeaf {}
+
+    /// A wrapper to keep a value, e.g. a tuple, as a single element in [flatten_tuple()].
+    ///
+    /// The wrapper is removed in the flattened tuple.
+    ///
+    /// ```
+    /// use tupleops::{flatten_tuple, Leaf};
+    ///
+    /// assert_eq!(
+    ///     flatten_tuple((1, Leaf((2, 3)), (4, 5))),
+    ///     (1, (2, 3), 4, 5),
+    /// );
+    /// ```
+    ///
+    /// See also: [flatten_tuple()], [FlattenLeaf].
+    #[cfg_attr(docsrs, doc(cfg(feature = "flatten")))]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Leaf<T>(pub T);
+
+    impl TupleFlatten<()> for () {
+        type Type = ();
+
+        #[inline(always)]
+        fn flatten_tuple((): ()) -> Self::Type {}
+    }
+
+    impl<T: FlattenLeaf> TupleFlatten<T> for T {
+        type Type = (T,);
+
+        #[inline(always)]
+        fn flatten_tuple(tpl: T) -> Self::Type {
+            (tpl,)
+        }
+    }
+
+    impl<T> TupleFlatten<Leaf<T>> for Leaf<T> {
+        type Type = (T,);
+
+        #[inline(always)]
+        fn flatten_tuple(tpl: Leaf<T>) -> Self::Type {
+            let Leaf(value) = tpl;
+            (value,)
+        }
+    }
+
+    macro_rules! impl_flatten_leaf {
+        ($($ty:ty)*) => {
+            $( impl FlattenLeaf for $ty {} )*
+        };
+    }
+
+    impl_flatten_leaf! {
+        bool char f32 f64
+        i8 i16 i32 i64 i128 isize
+        u8 u16 u32 u64 u128 usize
+        core::num::NonZeroI8 core::num::NonZeroI16 core::num::NonZeroI32 core::num::NonZeroI64
+        core::num::NonZeroI128 core::num::NonZeroIsize
+        core::num::NonZeroU8 core::num::NonZeroU16 core::num::NonZeroU32 core::num::NonZeroU64
+        core::num::NonZeroU128 core::num::NonZeroUsize
+        core::cmp::Ordering core::time::Duration core::marker::PhantomPinned
+        core::ops::RangeFull
+    }
+
+    impl<T: ?Sized> FlattenLeaf for &T {}
+
+    impl<T: ?Sized> FlattenLeaf for &mut T {}
+
+    impl<T, const N: usize> FlattenLeaf for [T; N] {}
+
+    impl<T> FlattenLeaf for Option<T> {}
+
+    impl<T, E> FlattenLeaf for Result<T, E> {}
+
+    impl<T: ?Sized> FlattenLeaf for PhantomData<T> {}
+
+    impl<T> FlattenLeaf for core::num::Wrapping<T> {}
+
+    impl<T> FlattenLeaf for core::cmp::Reverse<T> {}
+
+    impl<T: ?Sized> FlattenLeaf for core::cell::Cell<T> {}
+
+    impl<T: ?Sized> FlattenLeaf for core::cell::RefCell<T> {}
+
+    impl<T: ?Sized> FlattenLeaf for core::mem::ManuallyDrop<T> {}
+
+    impl<P> FlattenLeaf for core::pin::Pin<P> {}
+
+    impl<T> FlattenLeaf for core::ops::Range<T> {}
+
+    impl<T> FlattenLeaf for core::ops::RangeInclusive<T> {}
+
+    impl<T> FlattenLeaf for core::ops::RangeFrom<T> {}
+
+    impl<T> FlattenLeaf for core::ops::RangeTo<T> {}
+});
@@ -0,0 +1,114 @@
+crate::do_impl!(tuple_fold, {
+    /// Fold the elements of a tuple from the first to the last element with a folder.
+    ///
+    /// ```
+    /// use core::mem::size_of;
+    ///
+    /// use tupleops::{fold_tuple, TupleFolder};
+    ///
+    /// struct SizeOf;
+    ///
+    /// impl TupleFolder for SizeOf {
+    ///     type Acc = usize;
+    ///
+    ///     fn fold_elem<Elem>(&mut self, acc: usize, _: Elem) -> usize {
+    ///         acc + size_of::<Elem>()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(fold_tuple(SizeOf, 0, (1_u8, 2_u16, 3_u64)), 11);
+    /// assert_eq!(fold_tuple(SizeOf, 0, ()), 0);
+    /// ```
+    ///
+    /// See also: [TupleFolder], [TupleFold], [rfold_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "fold")))]
+    #[inline(always)]
+    pub fn fold_tuple<Folder: TupleFolder, Tpl>(
+        folder: Folder,
+        init: Folder::Acc,
+        tpl: Tpl,
+    ) -> Folder::Acc
+    where
+        (Folder, Tpl): TupleFold<Folder, Tpl>,
+    {
+        <(Folder, Tpl) as TupleFold<Folder, Tpl>>::fold_tuple(folder, init, tpl)
+    }
+
+    /// Fold the elements of a tuple from the last to the first element with a folder.
+    ///
+    /// ```
+    /// use core::any::type_name;
+    ///
+    /// use tupleops::{fold_tuple, rfold_tuple, TupleFolder};
+    ///
+    /// struct TypeNames;
+    ///
+    /// impl TupleFolder for TypeNames {
+    ///     type Acc = Vec<&'static str>;
+    ///
+    ///     fn fold_elem<Elem>(&mut self, mut acc: Self::Acc, _: Elem) -> Self::Acc {
+    ///         acc.push(type_name::<Elem>());
+    ///         acc
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     fold_tuple(TypeNames, vec![], (1_u8, 2_u16, 3_u32)),
+    ///     ["u8", "u16", "u32"],
+    /// );
+    /// assert_eq!(
+    ///     rfold_tuple(TypeNames, vec![], (1_u8, 2_u16, 3_u32)),
+    ///     ["u32", "u16", "u8"],
+    /// );
+    /// ```
+    ///
+    /// See also: [TupleFolder], [TupleFold], [fold_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "fold")))]
+    #[inline(always)]
+    pub fn rfold_tuple<Folder: TupleFolder, Tpl>(
+        folder: Folder,
+        init: Folder::Acc,
+        tpl: Tpl,
+    ) -> Folder::Acc
+    where
+        (Folder, Tpl): TupleFold<Folder, Tpl>,
+    {
+        <(Folder, Tpl) as TupleFold<Folder, Tpl>>::rfold_tuple(folder, init, tpl)
+    }
+
+    /// Helper trait to fold the elements of a tuple.
+    ///
+    /// See also: [fold_tuple()], [rfold_tuple()], [TupleFold].
+    #[cfg_attr(docsrs, doc(cfg(feature = "fold")))]
+    pub trait TupleFolder {
+        /// The type of the accumulator.
+        type Acc;
+
+        /// Fold an element into the accumulator.
+        fn fold_elem<Elem>(&mut self, acc: Self::Acc, elem: Elem) -> Self::Acc;
+    }
+
+    /// A [TupleFolder] and a tuple that are usable with [fold_tuple()] and [rfold_tuple()].
+    ///
+    /// See also: [fold_tuple()], [rfold_tuple()], [TupleFolder].
+    #[cfg_attr(docsrs, doc(cfg(feature = "fold")))]
+    pub trait TupleFold<Folder: TupleFolder, Tpl> {
+        #[doc(hidden)]
+        fn fold_tuple(folder: Folder, acc: Folder::Acc, tpl: Tpl) -> Folder::Acc;
+
+        #[doc(hidden)]
+        fn rfold_tuple(folder: Folder, acc: Folder::Acc, tpl: Tpl) -> Folder::Acc;
+    }
+
+    impl<Folder: TupleFolder> TupleFold<Folder, ()> for (Folder, ()) {
+        #[inline(always)]
+        fn fold_tuple(_folder: Folder, acc: Folder::Acc, _tpl: ()) -> Folder::Acc {
+            acc
+        }
+
+        #[inline(always)]
+        fn rfold_tuple(_folder: Folder, acc: Folder::Acc, _tpl: ()) -> Folder::Acc {
+            acc
+        }
+    }
+});
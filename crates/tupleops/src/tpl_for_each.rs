@@ -0,0 +1,134 @@
+crate::do_impl!(tuple_for_each, {
+    #[cfg(feature = "ref-mut")]
+    use crate::{ref_mut_tuple, RefMutTuple, TupleRefMut};
+    #[cfg(feature = "ref")]
+    use crate::{ref_tuple, RefTuple, TupleRef};
+
+    /// Visit every element of a tuple with a visitor.
+    ///
+    /// A visitor can be passed as `&mut visitor` to inspect its state afterwards.
+    ///
+    /// ```
+    /// use core::mem::size_of;
+    ///
+    /// use tupleops::{for_each_tuple, TupleVisitor};
+    ///
+    /// struct SizeOf(Vec<usize>);
+    ///
+    /// impl TupleVisitor for SizeOf {
+    ///     fn visit<Elem>(&mut self, _: Elem) {
+    ///         self.0.push(size_of::<Elem>());
+    ///     }
+    /// }
+    ///
+    /// let mut visitor = SizeOf(vec![]);
+    /// for_each_tuple(&mut visitor, (1_u8, 2_u16, 3_u64));
+    /// assert_eq!(visitor.0, [1, 2, 8]);
+    /// ```
+    ///
+    /// See also: [TupleVisitor], [TupleForEach].
+    #[cfg_attr(docsrs, doc(cfg(feature = "for-each")))]
+    #[inline(always)]
+    pub fn for_each_tuple<Visitor: TupleVisitor, Tpl>(visitor: Visitor, tpl: Tpl)
+    where
+        (Visitor, Tpl): TupleForEach<Visitor, Tpl>,
+    {
+        <(Visitor, Tpl) as TupleForEach<Visitor, Tpl>>::for_each_tuple(visitor, tpl)
+    }
+
+    /// Visit a reference to every element of a tuple with a visitor.
+    ///
+    /// ```
+    /// use core::any::type_name;
+    ///
+    /// use tupleops::{for_each_ref_tuple, TupleVisitor};
+    ///
+    /// struct TypeNames(Vec<&'static str>);
+    ///
+    /// impl TupleVisitor for TypeNames {
+    ///     fn visit<Elem>(&mut self, _: Elem) {
+    ///         self.0.push(type_name::<Elem>());
+    ///     }
+    /// }
+    ///
+    /// let mut visitor = TypeNames(vec![]);
+    /// for_each_ref_tuple(&mut visitor, &(1_u8, 2_u16));
+    /// assert_eq!(visitor.0, ["&u8", "&u16"]);
+    /// ```
+    ///
+    /// See also: [for_each_tuple()], [ref_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "for-each")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref")))]
+    #[cfg(feature = "ref")]
+    #[inline(always)]
+    pub fn for_each_ref_tuple<'a, Visitor: TupleVisitor, Tpl>(visitor: Visitor, tpl: &'a Tpl)
+    where
+        &'a Tpl: TupleRef<'a, Tpl>,
+        (Visitor, RefTuple<'a, Tpl>): TupleForEach<Visitor, RefTuple<'a, Tpl>>,
+    {
+        for_each_tuple(visitor, ref_tuple(tpl))
+    }
+
+    /// Visit a mutable reference to every element of a tuple with a visitor.
+    ///
+    /// ```
+    /// use core::any::type_name;
+    ///
+    /// use tupleops::{for_each_mut_tuple, TupleVisitor};
+    ///
+    /// struct TypeNames(Vec<&'static str>);
+    ///
+    /// impl TupleVisitor for TypeNames {
+    ///     fn visit<Elem>(&mut self, _: Elem) {
+    ///         self.0.push(type_name::<Elem>());
+    ///     }
+    /// }
+    ///
+    /// let mut visitor = TypeNames(vec![]);
+    /// for_each_mut_tuple(&mut visitor, &mut (1_u8, 2_u16));
+    /// assert_eq!(visitor.0, ["&mut u8", "&mut u16"]);
+    /// ```
+    ///
+    /// See also: [for_each_tuple()], [ref_mut_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "for-each")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref-mut")))]
+    #[cfg(feature = "ref-mut")]
+    #[inline(always)]
+    pub fn for_each_mut_tuple<'a, Visitor: TupleVisitor, Tpl>(visitor: Visitor, tpl: &'a mut Tpl)
+    where
+        &'a mut Tpl: TupleRefMut<'a, Tpl>,
+        (Visitor, RefMutTuple<'a, Tpl>): TupleForEach<Visitor, RefMutTuple<'a, Tpl>>,
+    {
+        for_each_tuple(visitor, ref_mut_tuple(tpl))
+    }
+
+    /// Helper trait to visit every element of a tuple.
+    ///
+    /// See also: [for_each_tuple()], [TupleForEach].
+    #[cfg_attr(docsrs, doc(cfg(feature = "for-each")))]
+    pub trait TupleVisitor {
+        /// Visit an element.
+        fn visit<Elem>(&mut self, elem: Elem);
+    }
+
+    impl<Visitor: TupleVisitor + ?Sized> TupleVisitor for &mut Visitor {
+        #[inline(always)]
+        fn visit<Elem>(&mut self, elem: Elem) {
+            Visitor::visit(self, elem)
+        }
+    }
+
+    /// A [TupleVisitor] and a tuple that are usable with [for_each_tuple()].
+    ///
+    /// See also: [for_each_tuple()], [TupleVisitor].
+    #[cfg_attr(docsrs, doc(cfg(feature = "for-each")))]
+    pub trait TupleForEach<Visitor: TupleVisitor, Tpl> {
+        #[doc(hidden)]
+        fn for_each_tuple(visitor: Visitor, tpl: Tpl);
+    }
+
+    impl<Visitor: TupleVisitor> TupleForEach<Visitor, ()> for (Visitor, ()) {
+        #[inline(always)]
+        fn for_each_tuple(_visitor: Visitor, _tpl: ()) {}
+    }
+});
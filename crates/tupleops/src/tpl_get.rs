@@ -0,0 +1,111 @@
+crate::do_impl!(tuple_get, {
+    /// The type of the N-th element of a tuple.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::Get;
+    ///
+    /// assert_same_types!(
+    ///     Get<(u8, u16, u32), 1>,
+    ///     u16,
+    /// );
+    /// ```
+    ///
+    /// See also: [get()], [get_ref()], [get_mut()], [TupleGet].
+    #[cfg_attr(docsrs, doc(cfg(feature = "get")))]
+    pub type Get<Tpl, const N: usize> = <Tpl as TupleGet<N>>::Type;
+
+    /// Extract the N-th element of a tuple.
+    ///
+    /// ```
+    /// use tupleops::get;
+    ///
+    /// assert_eq!(
+    ///     get::<0, _>((1, "two", 3.0)),
+    ///     1,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     get::<2, _>((1, "two", 3.0)),
+    ///     3.0,
+    /// );
+    /// ```
+    ///
+    /// See also: [Get], [get_ref()], [get_mut()], [TupleGet].
+    #[cfg_attr(docsrs, doc(cfg(feature = "get")))]
+    #[inline(always)]
+    pub fn get<const N: usize, Tpl>(tpl: Tpl) -> Get<Tpl, N>
+    where
+        Tpl: TupleGet<N>,
+    {
+        <Tpl as TupleGet<N>>::get(tpl)
+    }
+
+    /// Borrow the N-th element of a tuple.
+    ///
+    /// ```
+    /// use tupleops::get_ref;
+    ///
+    /// let tpl = (1, "two", 3.0);
+    /// assert_eq!(
+    ///     get_ref::<1, _>(&tpl),
+    ///     &"two",
+    /// );
+    /// ```
+    ///
+    /// See also: [Get], [get()], [get_mut()], [TupleGet].
+    #[cfg_attr(docsrs, doc(cfg(feature = "get")))]
+    #[inline(always)]
+    pub fn get_ref<const N: usize, Tpl>(tpl: &Tpl) -> &Get<Tpl, N>
+    where
+        Tpl: TupleGet<N>,
+    {
+        <Tpl as TupleGet<N>>::get_ref(tpl)
+    }
+
+    /// Mutably borrow the N-th element of a tuple.
+    ///
+    /// ```
+    /// use tupleops::get_mut;
+    ///
+    /// let mut tpl = (1, "two", 3.0);
+    /// *get_mut::<1, _>(&mut tpl) = "zwei";
+    /// assert_eq!(tpl, (1, "zwei", 3.0));
+    /// ```
+    ///
+    /// See also: [Get], [get()], [get_ref()], [TupleGet].
+    #[cfg_attr(docsrs, doc(cfg(feature = "get")))]
+    #[inline(always)]
+    pub fn get_mut<const N: usize, Tpl>(tpl: &mut Tpl) -> &mut Get<Tpl, N>
+    where
+        Tpl: TupleGet<N>,
+    {
+        <Tpl as TupleGet<N>>::get_mut(tpl)
+    }
+
+    /// A tuple that has an N-th element, usable with [get()], [get_ref()], and [get_mut()].
+    ///
+    /// An index that is out of bounds is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use tupleops::get;
+    ///
+    /// get::<3, _>((1, 2, 3));
+    /// ```
+    ///
+    /// See also: [get()], [get_ref()], [get_mut()], [Get].
+    #[cfg_attr(docsrs, doc(cfg(feature = "get")))]
+    pub trait TupleGet<const N: usize> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn get(tpl: Self) -> Self::Type;
+
+        #[doc(hidden)]
+        fn get_ref(tpl: &Self) -> &Self::Type;
+
+        #[doc(hidden)]
+        fn get_mut(tpl: &mut Self) -> &mut Self::Type;
+    }
+});
@@ -0,0 +1,82 @@
+crate::do_impl!(tuple_insert_at, {
+    /// The resulting type when an element is inserted into a tuple at index N.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::InsertAt;
+    ///
+    /// assert_same_types!(
+    ///     InsertAt<(u8, u32), 1, u16>,
+    ///     (u8, u16, u32),
+    /// );
+    /// ```
+    ///
+    /// See also: [insert_at()], [TupleInsertAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "insert-at")))]
+    pub type InsertAt<Tpl, const N: usize, Elem> = <Tpl as TupleInsertAt<Elem, N>>::Type;
+
+    /// Insert an element into a tuple, so that it becomes the N-th element.
+    ///
+    /// ```
+    /// use tupleops::insert_at;
+    ///
+    /// assert_eq!(
+    ///     insert_at::<1, _, _>((1, 3), 2),
+    ///     (1, 2, 3),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     insert_at::<0, _, _>((2, 3), 1),
+    ///     (1, 2, 3),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     insert_at::<2, _, _>((1, 2), 3),
+    ///     (1, 2, 3),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     insert_at::<0, _, _>((), 1),
+    ///     (1,),
+    /// );
+    /// ```
+    ///
+    /// See also: [InsertAt], [TupleInsertAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "insert-at")))]
+    #[inline(always)]
+    pub fn insert_at<const N: usize, Tpl, Elem>(tpl: Tpl, elem: Elem) -> InsertAt<Tpl, N, Elem>
+    where
+        Tpl: TupleInsertAt<Elem, N>,
+    {
+        <Tpl as TupleInsertAt<Elem, N>>::insert_at(tpl, elem)
+    }
+
+    /// A tuple that is usable with [insert_at()].
+    ///
+    /// An index larger than the length of the tuple is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use tupleops::insert_at;
+    ///
+    /// insert_at::<4, _, _>((1, 2, 3), 4);
+    /// ```
+    ///
+    /// See also: [insert_at()], [InsertAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "insert-at")))]
+    pub trait TupleInsertAt<Elem, const N: usize> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn insert_at(tpl: Self, elem: Elem) -> Self::Type;
+    }
+
+    impl<Elem> TupleInsertAt<Elem, 0> for () {
+        type Type = (Elem,);
+
+        #[inline(always)]
+        fn insert_at((): (), elem: Elem) -> Self::Type {
+            (elem,)
+        }
+    }
+});
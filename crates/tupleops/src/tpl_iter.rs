@@ -0,0 +1,109 @@
+crate::do_impl!(tuple_iter, {
+    use crate::tuple_to_array;
+    #[cfg(feature = "ref-mut")]
+    use crate::{ref_mut_tuple, RefMutTuple, TupleRefMut};
+    #[cfg(feature = "ref")]
+    use crate::{ref_tuple, RefTuple, TupleRef};
+
+    /// The iterator type over a non-empty tuple whose elements all have the same type.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::IterTuple;
+    ///
+    /// assert_same_types!(
+    ///     IterTuple<(u8, u8, u8)>,
+    ///     core::array::IntoIter<u8, 3>,
+    /// );
+    /// ```
+    ///
+    /// See also: [iter_tuple()], [TupleIter].
+    #[cfg_attr(docsrs, doc(cfg(feature = "iter")))]
+    pub type IterTuple<Tpl> = <Tpl as TupleIter<Tpl>>::Type;
+
+    /// Iterate over the elements of a non-empty tuple whose elements all have the same type.
+    ///
+    /// ```
+    /// use tupleops::iter_tuple;
+    ///
+    /// let mut iter = iter_tuple((1, 2, 3));
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// See also: [IterTuple], [TupleIter].
+    #[cfg_attr(docsrs, doc(cfg(feature = "iter")))]
+    #[inline(always)]
+    pub fn iter_tuple<Tpl>(tpl: Tpl) -> IterTuple<Tpl>
+    where
+        Tpl: TupleIter<Tpl>,
+    {
+        <Tpl as TupleIter<Tpl>>::iter_tuple(tpl)
+    }
+
+    /// Iterate over references to the elements of a non-empty tuple whose elements all have the
+    /// same type.
+    ///
+    /// ```
+    /// use tupleops::iter_ref_tuple;
+    ///
+    /// let tpl = (1, 2, 3);
+    /// assert_eq!(iter_ref_tuple(&tpl).len(), 3);
+    /// assert_eq!(iter_ref_tuple(&tpl).sum::<i32>(), 6);
+    /// ```
+    ///
+    /// See also: [iter_tuple()], [ref_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "iter")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref")))]
+    #[cfg(feature = "ref")]
+    #[inline(always)]
+    pub fn iter_ref_tuple<'a, Tpl>(tpl: &'a Tpl) -> IterTuple<RefTuple<'a, Tpl>>
+    where
+        &'a Tpl: TupleRef<'a, Tpl>,
+        RefTuple<'a, Tpl>: TupleIter<RefTuple<'a, Tpl>>,
+    {
+        iter_tuple(ref_tuple(tpl))
+    }
+
+    /// Iterate over mutable references to the elements of a non-empty tuple whose elements all
+    /// have the same type.
+    ///
+    /// ```
+    /// use tupleops::iter_mut_tuple;
+    ///
+    /// let mut tpl = (1, 2, 3);
+    /// for elem in iter_mut_tuple(&mut tpl) {
+    ///     *elem *= 10;
+    /// }
+    /// assert_eq!(tpl, (10, 20, 30));
+    /// ```
+    ///
+    /// See also: [iter_tuple()], [ref_mut_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "iter")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref-mut")))]
+    #[cfg(feature = "ref-mut")]
+    #[inline(always)]
+    pub fn iter_mut_tuple<'a, Tpl>(tpl: &'a mut Tpl) -> IterTuple<RefMutTuple<'a, Tpl>>
+    where
+        &'a mut Tpl: TupleRefMut<'a, Tpl>,
+        RefMutTuple<'a, Tpl>: TupleIter<RefMutTuple<'a, Tpl>>,
+    {
+        iter_tuple(ref_mut_tuple(tpl))
+    }
+
+    /// A non-empty tuple whose elements all have the same type, that is usable with
+    /// [iter_tuple()].
+    ///
+    /// See also: [iter_tuple()], [IterTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "iter")))]
+    pub trait TupleIter<Tpl> {
+        #[doc(hidden)]
+        type Type: ExactSizeIterator;
+
+        #[doc(hidden)]
+        fn iter_tuple(tpl: Tpl) -> Self::Type;
+    }
+});
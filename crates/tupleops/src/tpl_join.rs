@@ -0,0 +1,206 @@
+crate::do_impl!(tuple_join, {
+    use core::future::{poll_fn, Future};
+    use core::pin::pin;
+    use core::task::Poll;
+
+    /// The output of [join_tuple()], the tuple of the outputs of a tuple of futures.
+    ///
+    /// ```
+    /// use core::future::Ready;
+    ///
+    /// use same_types::assert_same_types;
+    /// use tupleops::JoinTuple;
+    ///
+    /// assert_same_types!(
+    ///     JoinTuple<(Ready<u8>, Ready<u16>)>,
+    ///     (u8, u16),
+    /// );
+    /// ```
+    ///
+    /// See also: [join_tuple()], [TupleJoin].
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub type JoinTuple<Tpl> = <Tpl as TupleJoin<Tpl>>::Type;
+
+    /// The output of [try_join_tuple()] if all futures returned [Ok].
+    ///
+    /// ```
+    /// use core::future::Ready;
+    ///
+    /// use same_types::assert_same_types;
+    /// use tupleops::TryJoinTuple;
+    ///
+    /// assert_same_types!(
+    ///     TryJoinTuple<(Ready<Result<u8, ()>>, Ready<Result<u16, ()>>)>,
+    ///     (u8, u16),
+    /// );
+    /// ```
+    ///
+    /// See also: [try_join_tuple()], [TupleTryJoin].
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub type TryJoinTuple<Tpl> = <Tpl as TupleTryJoin<Tpl>>::Type;
+
+    /// Await all futures of a tuple concurrently, and return the tuple of their outputs.
+    ///
+    /// ```
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake};
+    /// # use std::thread::{self, Thread};
+    /// #
+    /// # struct ThreadWaker(Thread);
+    /// #
+    /// # impl Wake for ThreadWaker {
+    /// #     fn wake(self: Arc<Self>) {
+    /// #         self.0.unpark();
+    /// #     }
+    /// # }
+    /// #
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let mut fut = pin!(fut);
+    /// #     let waker = Arc::new(ThreadWaker(thread::current())).into();
+    /// #     let mut cx = Context::from_waker(&waker);
+    /// #     loop {
+    /// #         match fut.as_mut().poll(&mut cx) {
+    /// #             Poll::Ready(output) => return output,
+    /// #             Poll::Pending => thread::park(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// use tupleops::join_tuple;
+    ///
+    /// async fn one() -> u8 {
+    ///     1
+    /// }
+    ///
+    /// async fn two() -> &'static str {
+    ///     "two"
+    /// }
+    ///
+    /// let output = block_on(join_tuple((one(), two(), async { 3.0 })));
+    /// assert_eq!(output, (1, "two", 3.0));
+    /// ```
+    ///
+    /// See also: [JoinTuple], [TupleJoin], [try_join_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    #[inline(always)]
+    pub fn join_tuple<Tpl>(tpl: Tpl) -> impl Future<Output = JoinTuple<Tpl>>
+    where
+        Tpl: TupleJoin<Tpl>,
+    {
+        <Tpl as TupleJoin<Tpl>>::join_tuple(tpl)
+    }
+
+    /// Await all futures of a non-empty tuple of futures that return [Result]s with the same
+    /// error type concurrently, and return the tuple of their [Ok] values.
+    ///
+    /// Return the first error as soon as any future returns an [Err].
+    /// The remaining futures are dropped without being polled to completion.
+    ///
+    /// ```
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake};
+    /// # use std::thread::{self, Thread};
+    /// #
+    /// # struct ThreadWaker(Thread);
+    /// #
+    /// # impl Wake for ThreadWaker {
+    /// #     fn wake(self: Arc<Self>) {
+    /// #         self.0.unpark();
+    /// #     }
+    /// # }
+    /// #
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let mut fut = pin!(fut);
+    /// #     let waker = Arc::new(ThreadWaker(thread::current())).into();
+    /// #     let mut cx = Context::from_waker(&waker);
+    /// #     loop {
+    /// #         match fut.as_mut().poll(&mut cx) {
+    /// #             Poll::Ready(output) => return output,
+    /// #             Poll::Pending => thread::park(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// use std::future::pending;
+    ///
+    /// use tupleops::try_join_tuple;
+    ///
+    /// let output = block_on(try_join_tuple((
+    ///     async { Ok::<_, &str>(1) },
+    ///     async { Ok("two") },
+    /// )));
+    /// assert_eq!(output, Ok((1, "two")));
+    ///
+    /// // The first future never completes, but the second one fails.
+    /// let output = block_on(try_join_tuple((
+    ///     pending::<Result<u8, &str>>(),
+    ///     async { Err::<&str, _>("error") },
+    /// )));
+    /// assert_eq!(output, Err("error"));
+    /// ```
+    ///
+    /// See also: [TryJoinTuple], [TupleTryJoin], [join_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    #[inline(always)]
+    pub fn try_join_tuple<Tpl>(
+        tpl: Tpl,
+    ) -> impl Future<Output = Result<TryJoinTuple<Tpl>, <Tpl as TupleTryJoin<Tpl>>::Error>>
+    where
+        Tpl: TupleTryJoin<Tpl>,
+    {
+        <Tpl as TupleTryJoin<Tpl>>::try_join_tuple(tpl)
+    }
+
+    /// A tuple of futures that is usable with [join_tuple()].
+    ///
+    /// The returned future is an `impl Future`, because it cannot be named without unsafe pin
+    /// projections.
+    ///
+    /// See also: [join_tuple()], [JoinTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub trait TupleJoin<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn join_tuple(tpl: Tpl) -> impl Future<Output = Self::Type>;
+    }
+
+    /// A non-empty tuple of futures that return [Result]s with the same error type, that is
+    /// usable with [try_join_tuple()].
+    ///
+    /// Futures with different error types cannot be joined:
+    ///
+    /// ```compile_fail
+    /// use tupleops::try_join_tuple;
+    ///
+    /// let _ = try_join_tuple((
+    ///     async { Ok::<u8, u8>(1) },
+    ///     async { Ok::<u8, u16>(2) },
+    /// ));
+    /// ```
+    ///
+    /// See also: [try_join_tuple()], [TryJoinTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub trait TupleTryJoin<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        type Error;
+
+        #[doc(hidden)]
+        fn try_join_tuple(tpl: Tpl) -> impl Future<Output = Result<Self::Type, Self::Error>>;
+    }
+
+    impl TupleJoin<()> for () {
+        type Type = ();
+
+        #[inline(always)]
+        fn join_tuple((): ()) -> impl Future<Output = Self::Type> {
+            core::future::ready(())
+        }
+    }
+});
@@ -1,4 +1,4 @@
-crate::do_impl!("length", tuple_length, {
+crate::do_impl!(tuple_length, {
     /// Return the length of a tuple.
     ///
     /// ```
@@ -12,8 +12,6 @@ crate::do_impl!("length", tuple_length, {
     ///
     /// See also: [TupleLength].
     #[cfg_attr(docsrs, doc(cfg(feature = "length")))]
-    #[cfg_attr(docsrs, doc(cfg(feature = "feature-const_fn_trait_bound")))]
-    #[cfg(feature = "feature-const_fn_trait_bound")]
     pub const fn length<Tpl>(_: &Tpl) -> usize
     where
         Tpl: TupleLength,
@@ -1,4 +1,4 @@
-crate::do_impl!("map", tuple_map, {
+crate::do_impl!(tuple_map, {
     /// The type of a tuple when element-wise mapped with a mapper.
     ///
     /// See also: [map_tuple()], [TupleMapper], [TupleMap].
@@ -7,8 +7,6 @@ crate::do_impl!("map", tuple_map, {
     /// Element-wise map a tuple with a mapper.
     ///
     /// ```
-    /// #![feature(generic_associated_types)]
-    ///
     /// use tupleops::{TupleMapper, map_tuple};
     ///
     /// struct MyTupleEnum(usize);
@@ -0,0 +1,99 @@
+crate::do_impl!(tuple_map_homogeneous, {
+    /// The resulting type when every element of a non-empty, homogeneous tuple is mapped to U.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::MapHomogeneous;
+    ///
+    /// assert_same_types!(
+    ///     MapHomogeneous<(u8, u8, u8), String>,
+    ///     (String, String, String),
+    /// );
+    /// ```
+    ///
+    /// See also: [map_homogeneous()], [map_indexed()], [TupleMapHomogeneous].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-homogeneous")))]
+    pub type MapHomogeneous<Tpl, U> = <Tpl as TupleMapHomogeneous<Tpl>>::Type<U>;
+
+    /// Map every element of a non-empty tuple whose elements all have the same type with a
+    /// closure.
+    ///
+    /// The elements are mapped from first to last.
+    ///
+    /// ```
+    /// use tupleops::map_homogeneous;
+    ///
+    /// assert_eq!(
+    ///     map_homogeneous((1, 2, 3), |x| x * 10),
+    ///     (10, 20, 30),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     map_homogeneous(("1", "2"), |s| s.parse::<u8>()),
+    ///     (Ok(1), Ok(2)),
+    /// );
+    /// ```
+    ///
+    /// See also: [MapHomogeneous], [TupleMapHomogeneous], [map_indexed()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-homogeneous")))]
+    #[inline(always)]
+    pub fn map_homogeneous<Tpl, U, F>(tpl: Tpl, f: F) -> MapHomogeneous<Tpl, U>
+    where
+        Tpl: TupleMapHomogeneous<Tpl>,
+        F: FnMut(<Tpl as TupleMapHomogeneous<Tpl>>::Elem) -> U,
+    {
+        <Tpl as TupleMapHomogeneous<Tpl>>::map_homogeneous(tpl, f)
+    }
+
+    /// Map every element of a non-empty tuple whose elements all have the same type with a
+    /// closure that also receives the index of the element.
+    ///
+    /// The elements are mapped from first to last.
+    ///
+    /// ```
+    /// use tupleops::map_indexed;
+    ///
+    /// assert_eq!(
+    ///     map_indexed(("a", "b", "c"), |i, s| format!("{}{}", s, i)),
+    ///     ("a0".to_owned(), "b1".to_owned(), "c2".to_owned()),
+    /// );
+    /// ```
+    ///
+    /// See also: [MapHomogeneous], [TupleMapHomogeneous], [map_homogeneous()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-homogeneous")))]
+    #[inline(always)]
+    pub fn map_indexed<Tpl, U, F>(tpl: Tpl, f: F) -> MapHomogeneous<Tpl, U>
+    where
+        Tpl: TupleMapHomogeneous<Tpl>,
+        F: FnMut(usize, <Tpl as TupleMapHomogeneous<Tpl>>::Elem) -> U,
+    {
+        <Tpl as TupleMapHomogeneous<Tpl>>::map_indexed(tpl, f)
+    }
+
+    /// A non-empty tuple whose elements all have the same type, that is usable with
+    /// [map_homogeneous()] and [map_indexed()].
+    ///
+    /// Tuples with elements of different types cannot be mapped with a closure:
+    ///
+    /// ```compile_fail
+    /// use tupleops::map_homogeneous;
+    ///
+    /// map_homogeneous((1_u8, 2_u16), |x| x);
+    /// ```
+    ///
+    /// See also: [map_homogeneous()], [map_indexed()], [MapHomogeneous].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-homogeneous")))]
+    pub trait TupleMapHomogeneous<Tpl> {
+        /// The type of every element of the tuple.
+        type Elem;
+
+        #[doc(hidden)]
+        type Type<U>;
+
+        #[doc(hidden)]
+        fn map_homogeneous<U, F: FnMut(Self::Elem) -> U>(tpl: Tpl, f: F) -> Self::Type<U>;
+
+        #[doc(hidden)]
+        fn map_indexed<U, F: FnMut(usize, Self::Elem) -> U>(tpl: Tpl, f: F) -> Self::Type<U>;
+    }
+});
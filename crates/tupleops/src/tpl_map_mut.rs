@@ -0,0 +1,85 @@
+crate::do_impl!(tuple_map_mut, {
+    use crate::{ref_mut_tuple, RefMutTuple, TupleRefMut};
+
+    /// The type of a reference to a mutable tuple when element-wise mapped with a
+    /// [TupleMutMapper].
+    ///
+    /// See also: [map_mut_tuple()], [TupleMutMapper], [TupleMapMut].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-mut")))]
+    pub type MapMutTuple<'a, Mapper, Tpl> =
+        <(Mapper, RefMutTuple<'a, Tpl>) as TupleMapMut<'a, Mapper, RefMutTuple<'a, Tpl>>>::Type;
+
+    /// Element-wise map a reference to a mutable tuple with a mapper that receives mutable
+    /// references.
+    ///
+    /// The mapped elements may borrow from the tuple, but not from the mapper.
+    ///
+    /// ```
+    /// use tupleops::{map_mut_tuple, TupleMutMapper};
+    ///
+    /// struct AsSlice;
+    ///
+    /// impl<'a> TupleMutMapper<'a> for AsSlice {
+    ///     type MapElem<Elem: 'a> = &'a mut [Elem];
+    ///
+    ///     fn map_elem<Elem: 'a>(&mut self, elem: &'a mut Elem) -> Self::MapElem<Elem> {
+    ///         core::slice::from_mut(elem)
+    ///     }
+    /// }
+    ///
+    /// let mut tpl = (1, "two", 3.0);
+    /// let (a, b, c) = map_mut_tuple(AsSlice, &mut tpl);
+    /// a[0] = 10;
+    /// b[0] = "twenty";
+    /// c[0] = 30.0;
+    /// assert_eq!(tpl, (10, "twenty", 30.0));
+    /// ```
+    ///
+    /// See also: [MapMutTuple], [TupleMutMapper], [TupleMapMut], [ref_mut_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-mut")))]
+    #[inline(always)]
+    pub fn map_mut_tuple<'a, Mapper, Tpl>(
+        mapper: Mapper,
+        tpl: &'a mut Tpl,
+    ) -> MapMutTuple<'a, Mapper, Tpl>
+    where
+        Mapper: TupleMutMapper<'a>,
+        &'a mut Tpl: TupleRefMut<'a, Tpl>,
+        (Mapper, RefMutTuple<'a, Tpl>): TupleMapMut<'a, Mapper, RefMutTuple<'a, Tpl>>,
+    {
+        let tpl = ref_mut_tuple(tpl);
+        <(Mapper, _) as TupleMapMut<'a, Mapper, _>>::map_mut_tuple(mapper, tpl)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-mut")))]
+    /// Helper trait to element-wise map a reference to a mutable tuple.
+    ///
+    /// See also: [map_mut_tuple()], [MapMutTuple], [TupleMapMut].
+    pub trait TupleMutMapper<'a> {
+        /// The result type after mapping a mutable reference to Elem.
+        type MapElem<Elem: 'a>;
+
+        /// Map a mutable reference to an element.
+        fn map_elem<Elem: 'a>(&mut self, elem: &'a mut Elem) -> Self::MapElem<Elem>;
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-mut")))]
+    /// A [TupleMutMapper] and a tuple of mutable references that are usable with
+    /// [map_mut_tuple()].
+    ///
+    /// See also: [map_mut_tuple()], [MapMutTuple], [TupleMutMapper].
+    pub trait TupleMapMut<'a, Mapper: TupleMutMapper<'a>, Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn map_mut_tuple(mapper: Mapper, tpl: Tpl) -> Self::Type;
+    }
+
+    impl<'a, Mapper: TupleMutMapper<'a>> TupleMapMut<'a, Mapper, ()> for (Mapper, ()) {
+        type Type = ();
+
+        #[inline(always)]
+        fn map_mut_tuple(_mapper: Mapper, _tpl: ()) -> Self::Type {}
+    }
+});
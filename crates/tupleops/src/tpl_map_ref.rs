@@ -0,0 +1,84 @@
+crate::do_impl!(tuple_map_ref, {
+    use crate::{ref_tuple, RefTuple, TupleRef};
+
+    /// The type of a reference to a tuple when element-wise mapped with a [TupleRefMapper].
+    ///
+    /// See also: [map_ref_tuple()], [TupleRefMapper], [TupleMapRef].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-ref")))]
+    pub type MapRefTuple<'a, Mapper, Tpl> =
+        <(Mapper, RefTuple<'a, Tpl>) as TupleMapRef<'a, Mapper, RefTuple<'a, Tpl>>>::Type;
+
+    /// Element-wise map a reference to a tuple with a mapper that receives references.
+    ///
+    /// The mapped elements may borrow from the tuple, but not from the mapper.
+    ///
+    /// ```
+    /// use tupleops::{map_ref_tuple, TupleRefMapper};
+    ///
+    /// struct Enumerate(usize);
+    ///
+    /// impl<'a> TupleRefMapper<'a> for Enumerate {
+    ///     type MapElem<Elem: 'a> = (usize, &'a Elem);
+    ///
+    ///     fn map_elem<Elem: 'a>(&mut self, elem: &'a Elem) -> Self::MapElem<Elem> {
+    ///         let index = self.0;
+    ///         self.0 += 1;
+    ///         (index, elem)
+    ///     }
+    /// }
+    ///
+    /// let tpl = (1, "two", 3.0);
+    /// let mapped = map_ref_tuple(Enumerate(0), &tpl);
+    /// assert_eq!(
+    ///     mapped,
+    ///     ((0, &1), (1, &"two"), (2, &3.0)),
+    /// );
+    /// ```
+    ///
+    /// See also: [MapRefTuple], [TupleRefMapper], [TupleMapRef], [ref_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-ref")))]
+    #[inline(always)]
+    pub fn map_ref_tuple<'a, Mapper, Tpl>(
+        mapper: Mapper,
+        tpl: &'a Tpl,
+    ) -> MapRefTuple<'a, Mapper, Tpl>
+    where
+        Mapper: TupleRefMapper<'a>,
+        &'a Tpl: TupleRef<'a, Tpl>,
+        (Mapper, RefTuple<'a, Tpl>): TupleMapRef<'a, Mapper, RefTuple<'a, Tpl>>,
+    {
+        let tpl = ref_tuple(tpl);
+        <(Mapper, _) as TupleMapRef<'a, Mapper, _>>::map_ref_tuple(mapper, tpl)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-ref")))]
+    /// Helper trait to element-wise map a reference to a tuple.
+    ///
+    /// See also: [map_ref_tuple()], [MapRefTuple], [TupleMapRef].
+    pub trait TupleRefMapper<'a> {
+        /// The result type after mapping a reference to Elem.
+        type MapElem<Elem: 'a>;
+
+        /// Map a reference to an element.
+        fn map_elem<Elem: 'a>(&mut self, elem: &'a Elem) -> Self::MapElem<Elem>;
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "map-ref")))]
+    /// A [TupleRefMapper] and a tuple of references that are usable with [map_ref_tuple()].
+    ///
+    /// See also: [map_ref_tuple()], [MapRefTuple], [TupleRefMapper].
+    pub trait TupleMapRef<'a, Mapper: TupleRefMapper<'a>, Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn map_ref_tuple(mapper: Mapper, tpl: Tpl) -> Self::Type;
+    }
+
+    impl<'a, Mapper: TupleRefMapper<'a>> TupleMapRef<'a, Mapper, ()> for (Mapper, ()) {
+        type Type = ();
+
+        #[inline(always)]
+        fn map_ref_tuple(_mapper: Mapper, _tpl: ()) -> Self::Type {}
+    }
+});
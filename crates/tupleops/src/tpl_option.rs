@@ -1,4 +1,4 @@
-crate::do_impl!("option", tuple_option, {
+crate::do_impl!(tuple_option, {
     /// The resulting tuple when all elements are wrapped in [Option].
     ///
     /// ```
@@ -1,4 +1,4 @@
-crate::do_impl!("prepend", tuple_prepend, {
+crate::do_impl!(tuple_prepend, {
     /// The resulting type when an element is prepended to a tuple.
     ///
     /// ```
@@ -1,4 +1,4 @@
-crate::do_impl!("ref", tuple_ref, {
+crate::do_impl!(tuple_ref, {
     /// The resulting type when every element of this reference to a tuple is turned into a
     /// reference.
     ///
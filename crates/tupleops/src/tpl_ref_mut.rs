@@ -1,4 +1,4 @@
-crate::do_impl!("ref-mut", tuple_ref_mut, {
+crate::do_impl!(tuple_ref_mut, {
     /// The resulting type when every element of this reference to a mutable tuple is turned into
     /// a mutable reference.
     ///
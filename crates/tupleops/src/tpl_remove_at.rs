@@ -0,0 +1,71 @@
+crate::do_impl!(tuple_remove_at, {
+    /// The resulting tuple of the removed element and the remaining tuple when the N-th element
+    /// is removed from a tuple.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::RemoveAt;
+    ///
+    /// assert_same_types!(
+    ///     RemoveAt<(u8, u16, u32), 1>,
+    ///     (u16, (u8, u32)),
+    /// );
+    /// ```
+    ///
+    /// See also: [remove_at()], [TupleRemoveAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "remove-at")))]
+    pub type RemoveAt<Tpl, const N: usize> = (
+        <Tpl as TupleRemoveAt<N>>::Elem,
+        <Tpl as TupleRemoveAt<N>>::Rest,
+    );
+
+    /// Remove the N-th element of a tuple, and return a tuple of the removed element and the
+    /// remaining elements.
+    ///
+    /// ```
+    /// use tupleops::remove_at;
+    ///
+    /// assert_eq!(
+    ///     remove_at::<1, _>((1, 2, 3)),
+    ///     (2, (1, 3)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     remove_at::<0, _>((1,)),
+    ///     (1, ()),
+    /// );
+    /// ```
+    ///
+    /// See also: [RemoveAt], [TupleRemoveAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "remove-at")))]
+    #[inline(always)]
+    pub fn remove_at<const N: usize, Tpl>(tpl: Tpl) -> RemoveAt<Tpl, N>
+    where
+        Tpl: TupleRemoveAt<N>,
+    {
+        <Tpl as TupleRemoveAt<N>>::remove_at(tpl)
+    }
+
+    /// A tuple that is usable with [remove_at()].
+    ///
+    /// An index that is out of bounds is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use tupleops::remove_at;
+    ///
+    /// remove_at::<3, _>((1, 2, 3));
+    /// ```
+    ///
+    /// See also: [remove_at()], [RemoveAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "remove-at")))]
+    pub trait TupleRemoveAt<const N: usize> {
+        #[doc(hidden)]
+        type Elem;
+
+        #[doc(hidden)]
+        type Rest;
+
+        #[doc(hidden)]
+        fn remove_at(tpl: Self) -> (Self::Elem, Self::Rest);
+    }
+});
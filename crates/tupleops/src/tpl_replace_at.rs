@@ -0,0 +1,68 @@
+crate::do_impl!(tuple_replace_at, {
+    /// The resulting tuple of the new tuple and the old element when the N-th element of a tuple
+    /// is replaced with an element of type New.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::ReplaceAt;
+    ///
+    /// assert_same_types!(
+    ///     ReplaceAt<(u8, u16, u32), 1, &'static str>,
+    ///     ((u8, &'static str, u32), u16),
+    /// );
+    /// ```
+    ///
+    /// See also: [replace_at()], [TupleReplaceAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "replace-at")))]
+    pub type ReplaceAt<Tpl, const N: usize, New> = (
+        <Tpl as TupleReplaceAt<New, N>>::Type,
+        <Tpl as TupleReplaceAt<New, N>>::Old,
+    );
+
+    /// Replace the N-th element of a tuple, and return a tuple of the new tuple and the old
+    /// element.
+    ///
+    /// The new element does not need to have the same type as the old element.
+    ///
+    /// ```
+    /// use tupleops::replace_at;
+    ///
+    /// assert_eq!(
+    ///     replace_at::<1, _, _>((1, 2, 3), "two"),
+    ///     ((1, "two", 3), 2),
+    /// );
+    /// ```
+    ///
+    /// See also: [ReplaceAt], [TupleReplaceAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "replace-at")))]
+    #[inline(always)]
+    pub fn replace_at<const N: usize, Tpl, New>(tpl: Tpl, new: New) -> ReplaceAt<Tpl, N, New>
+    where
+        Tpl: TupleReplaceAt<New, N>,
+    {
+        <Tpl as TupleReplaceAt<New, N>>::replace_at(tpl, new)
+    }
+
+    /// A tuple that is usable with [replace_at()].
+    ///
+    /// An index that is out of bounds is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use tupleops::replace_at;
+    ///
+    /// replace_at::<3, _, _>((1, 2, 3), 4);
+    /// ```
+    ///
+    /// See also: [replace_at()], [ReplaceAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "replace-at")))]
+    pub trait TupleReplaceAt<New, const N: usize> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        type Old;
+
+        #[doc(hidden)]
+        fn replace_at(tpl: Self, new: New) -> (Self::Type, Self::Old);
+    }
+});
@@ -0,0 +1,76 @@
+crate::do_impl!(tuple_result, {
+    /// The resulting tuple when all elements are wrapped in [Result] with the error type E.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::ResultTuple;
+    ///
+    /// assert_same_types!(
+    ///     ResultTuple<(u8, u16, u32), ()>,
+    ///     (Result<u8, ()>, Result<u16, ()>, Result<u32, ()>),
+    /// );
+    /// ```
+    ///
+    /// See also: [result_tuple()], [TupleResult].
+    #[cfg_attr(docsrs, doc(cfg(feature = "result")))]
+    pub type ResultTuple<Tpl, E> = <Tpl as TupleResult<Tpl, E>>::Type;
+
+    /// Element-wise wrap the element of a tuple in [Result].
+    ///
+    /// If the input is an error, then every element of the result is a clone of the error.
+    ///
+    /// ```
+    /// use tupleops::result_tuple;
+    ///
+    /// assert_eq!(
+    ///     result_tuple(Result::<_, ()>::Ok((1, 2, 3))),
+    ///     (Ok(1), Ok(2), Ok(3)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     result_tuple(Result::<(u8, u16, u32), _>::Err("error")),
+    ///     (Err("error"), Err("error"), Err("error")),
+    /// );
+    /// ```
+    ///
+    /// See also: [ResultTuple], [TupleResult].
+    #[cfg_attr(docsrs, doc(cfg(feature = "result")))]
+    #[inline(always)]
+    pub fn result_tuple<Tpl, E>(tpl: Result<Tpl, E>) -> ResultTuple<Tpl, E>
+    where
+        Tpl: TupleResult<Tpl, E>,
+    {
+        <Tpl as TupleResult<Tpl, E>>::result_tuple(tpl)
+    }
+
+    /// A tuple that is usable with [result_tuple()].
+    ///
+    /// The error type must implement [Clone]:
+    ///
+    /// ```compile_fail
+    /// use tupleops::result_tuple;
+    ///
+    /// struct NotClone;
+    ///
+    /// result_tuple(Result::<(u8, u16), _>::Err(NotClone));
+    /// ```
+    ///
+    /// See also: [result_tuple()], [ResultTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "result")))]
+    pub trait TupleResult<Tpl, E> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn result_tuple(tpl: Result<Tpl, E>) -> Self::Type;
+    }
+
+    impl<E> TupleResult<(), E> for () {
+        type Type = ();
+
+        #[inline(always)]
+        fn result_tuple(tpl: Result<(), E>) -> Self::Type {
+            let _ = tpl;
+        }
+    }
+});
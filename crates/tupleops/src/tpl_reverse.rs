@@ -0,0 +1,116 @@
+crate::do_impl!(tuple_reverse, {
+    #[cfg(feature = "ref-mut")]
+    use crate::{ref_mut_tuple, RefMutTuple, TupleRefMut};
+    #[cfg(feature = "ref")]
+    use crate::{ref_tuple, RefTuple, TupleRef};
+
+    /// The resulting type when the order of the elements of a tuple is reversed.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::ReverseTuple;
+    ///
+    /// assert_same_types!(
+    ///     ReverseTuple<(u8, u16, u32)>,
+    ///     (u32, u16, u8),
+    /// );
+    /// ```
+    ///
+    /// See also: [reverse_tuple()], [TupleReverse].
+    #[cfg_attr(docsrs, doc(cfg(feature = "reverse")))]
+    pub type ReverseTuple<Tpl> = <Tpl as TupleReverse<Tpl>>::Type;
+
+    /// Reverse the order of the elements of a tuple.
+    ///
+    /// ```
+    /// use tupleops::reverse_tuple;
+    ///
+    /// assert_eq!(
+    ///     reverse_tuple((1, "two", 3.0)),
+    ///     (3.0, "two", 1),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     reverse_tuple(()),
+    ///     (),
+    /// );
+    /// ```
+    ///
+    /// See also: [ReverseTuple], [TupleReverse].
+    #[cfg_attr(docsrs, doc(cfg(feature = "reverse")))]
+    #[inline(always)]
+    pub fn reverse_tuple<Tpl>(tpl: Tpl) -> ReverseTuple<Tpl>
+    where
+        Tpl: TupleReverse<Tpl>,
+    {
+        <Tpl as TupleReverse<Tpl>>::reverse_tuple(tpl)
+    }
+
+    /// Turn a reference to a tuple into a reversed tuple of references.
+    ///
+    /// ```
+    /// use tupleops::reverse_ref_tuple;
+    ///
+    /// assert_eq!(
+    ///     reverse_ref_tuple(&(1, "two", 3.0)),
+    ///     (&3.0, &"two", &1),
+    /// );
+    /// ```
+    ///
+    /// See also: [reverse_tuple()], [ref_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "reverse")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref")))]
+    #[cfg(feature = "ref")]
+    #[inline(always)]
+    pub fn reverse_ref_tuple<'a, Tpl>(tpl: &'a Tpl) -> ReverseTuple<RefTuple<'a, Tpl>>
+    where
+        &'a Tpl: TupleRef<'a, Tpl>,
+        RefTuple<'a, Tpl>: TupleReverse<RefTuple<'a, Tpl>>,
+    {
+        reverse_tuple(ref_tuple(tpl))
+    }
+
+    /// Turn a reference to a mutable tuple into a reversed tuple of mutable references.
+    ///
+    /// ```
+    /// use tupleops::reverse_ref_mut_tuple;
+    ///
+    /// let mut tpl = (1, "two", 3.0);
+    /// let (c, _, a) = reverse_ref_mut_tuple(&mut tpl);
+    /// *a = 10;
+    /// *c = 30.0;
+    /// assert_eq!(tpl, (10, "two", 30.0));
+    /// ```
+    ///
+    /// See also: [reverse_tuple()], [ref_mut_tuple()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "reverse")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ref-mut")))]
+    #[cfg(feature = "ref-mut")]
+    #[inline(always)]
+    pub fn reverse_ref_mut_tuple<'a, Tpl>(tpl: &'a mut Tpl) -> ReverseTuple<RefMutTuple<'a, Tpl>>
+    where
+        &'a mut Tpl: TupleRefMut<'a, Tpl>,
+        RefMutTuple<'a, Tpl>: TupleReverse<RefMutTuple<'a, Tpl>>,
+    {
+        reverse_tuple(ref_mut_tuple(tpl))
+    }
+
+    /// A tuple that is usable with [reverse_tuple()].
+    ///
+    /// See also: [reverse_tuple()], [ReverseTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "reverse")))]
+    pub trait TupleReverse<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn reverse_tuple(tpl: Tpl) -> Self::Type;
+    }
+
+    impl TupleReverse<()> for () {
+        type Type = ();
+
+        #[inline(always)]
+        fn reverse_tuple((): ()) -> Self::Type {}
+    }
+});
@@ -0,0 +1,149 @@
+crate::do_impl!(tuple_rotate, {
+    /// The resulting type when a tuple is rotated to the left by N elements.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::RotateLeft;
+    ///
+    /// assert_same_types!(
+    ///     RotateLeft<(u8, u16, u32), 1>,
+    ///     (u16, u32, u8),
+    /// );
+    /// ```
+    ///
+    /// See also: [rotate_left()], [TupleRotateLeft].
+    #[cfg_attr(docsrs, doc(cfg(feature = "rotate")))]
+    pub type RotateLeft<Tpl, const N: usize> = <Tpl as TupleRotateLeft<N>>::Type;
+
+    /// The resulting type when a tuple is rotated to the right by N elements.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::RotateRight;
+    ///
+    /// assert_same_types!(
+    ///     RotateRight<(u8, u16, u32), 1>,
+    ///     (u32, u8, u16),
+    /// );
+    /// ```
+    ///
+    /// See also: [rotate_right()], [TupleRotateRight].
+    #[cfg_attr(docsrs, doc(cfg(feature = "rotate")))]
+    pub type RotateRight<Tpl, const N: usize> = <Tpl as TupleRotateRight<N>>::Type;
+
+    /// Rotate a tuple to the left, i.e. move the first N elements to the end of the tuple.
+    ///
+    /// ```
+    /// use tupleops::rotate_left;
+    ///
+    /// assert_eq!(
+    ///     rotate_left::<1, _>((1, 2, 3, 4)),
+    ///     (2, 3, 4, 1),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     rotate_left::<3, _>((1, 2, 3, 4)),
+    ///     (4, 1, 2, 3),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     rotate_left::<4, _>((1, 2, 3, 4)),
+    ///     (1, 2, 3, 4),
+    /// );
+    /// ```
+    ///
+    /// See also: [RotateLeft], [TupleRotateLeft], [rotate_right()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "rotate")))]
+    #[inline(always)]
+    pub fn rotate_left<const N: usize, Tpl>(tpl: Tpl) -> RotateLeft<Tpl, N>
+    where
+        Tpl: TupleRotateLeft<N>,
+    {
+        <Tpl as TupleRotateLeft<N>>::rotate_left(tpl)
+    }
+
+    /// Rotate a tuple to the right, i.e. move the last N elements to the start of the tuple.
+    ///
+    /// ```
+    /// use tupleops::rotate_right;
+    ///
+    /// assert_eq!(
+    ///     rotate_right::<1, _>((1, 2, 3, 4)),
+    ///     (4, 1, 2, 3),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     rotate_right::<3, _>((1, 2, 3, 4)),
+    ///     (2, 3, 4, 1),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     rotate_right::<0, _>((1, 2, 3, 4)),
+    ///     (1, 2, 3, 4),
+    /// );
+    /// ```
+    ///
+    /// See also: [RotateRight], [TupleRotateRight], [rotate_left()].
+    #[cfg_attr(docsrs, doc(cfg(feature = "rotate")))]
+    #[inline(always)]
+    pub fn rotate_right<const N: usize, Tpl>(tpl: Tpl) -> RotateRight<Tpl, N>
+    where
+        Tpl: TupleRotateRight<N>,
+    {
+        <Tpl as TupleRotateRight<N>>::rotate_right(tpl)
+    }
+
+    /// A tuple that is usable with [rotate_left()].
+    ///
+    /// Rotating by more elements than the tuple contains is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use tupleops::rotate_left;
+    ///
+    /// rotate_left::<4, _>((1, 2, 3));
+    /// ```
+    ///
+    /// See also: [rotate_left()], [RotateLeft].
+    #[cfg_attr(docsrs, doc(cfg(feature = "rotate")))]
+    pub trait TupleRotateLeft<const N: usize> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn rotate_left(tpl: Self) -> Self::Type;
+    }
+
+    /// A tuple that is usable with [rotate_right()].
+    ///
+    /// Rotating by more elements than the tuple contains is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use tupleops::rotate_right;
+    ///
+    /// rotate_right::<4, _>((1, 2, 3));
+    /// ```
+    ///
+    /// See also: [rotate_right()], [RotateRight].
+    #[cfg_attr(docsrs, doc(cfg(feature = "rotate")))]
+    pub trait TupleRotateRight<const N: usize> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn rotate_right(tpl: Self) -> Self::Type;
+    }
+
+    impl TupleRotateLeft<0> for () {
+        type Type = ();
+
+        #[inline(always)]
+        fn rotate_left((): ()) -> Self::Type {}
+    }
+
+    impl TupleRotateRight<0> for () {
+        type Type = ();
+
+        #[inline(always)]
+        fn rotate_right((): ()) -> Self::Type {}
+    }
+});
@@ -0,0 +1,109 @@
+crate::do_impl!(tuple_split_at, {
+    /// The resulting tuple of two tuples when a tuple is split at index N.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::SplitAt;
+    ///
+    /// assert_same_types!(
+    ///     SplitAt<(u8, u16, u32), 1>,
+    ///     ((u8,), (u16, u32)),
+    /// );
+    ///
+    /// assert_same_types!(
+    ///     SplitAt<(u8, u16, u32), 0>,
+    ///     ((), (u8, u16, u32)),
+    /// );
+    ///
+    /// assert_same_types!(
+    ///     SplitAt<(u8, u16, u32), 3>,
+    ///     ((u8, u16, u32), ()),
+    /// );
+    /// ```
+    ///
+    /// See also: [split_at()], [TupleSplitAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "split-at")))]
+    pub type SplitAt<Tpl, const N: usize> = (
+        <Tpl as TupleSplitAt<N>>::Front,
+        <Tpl as TupleSplitAt<N>>::Back,
+    );
+
+    /// Split a tuple into the first N elements and the remaining elements.
+    ///
+    /// This is the inverse operation of `concat_tuples()`.
+    ///
+    /// ```
+    /// use tupleops::split_at;
+    ///
+    /// assert_eq!(
+    ///     split_at::<2, _>((1, 2, 3, 4, 5)),
+    ///     ((1, 2), (3, 4, 5)),
+    /// );
+    ///
+    /// # #[cfg(feature = "concat")] {
+    /// # use tupleops::concat_tuples;
+    /// assert_eq!(
+    ///     split_at::<2, _>(concat_tuples((1, 2), (3, 4, 5))),
+    ///     ((1, 2), (3, 4, 5)),
+    /// );
+    /// # }
+    ///
+    /// assert_eq!(
+    ///     split_at::<0, _>((1, 2, 3)),
+    ///     ((), (1, 2, 3)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     split_at::<3, _>((1, 2, 3)),
+    ///     ((1, 2, 3), ()),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     split_at::<0, _>(()),
+    ///     ((), ()),
+    /// );
+    /// ```
+    ///
+    /// See also: [SplitAt], [TupleSplitAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "split-at")))]
+    #[inline(always)]
+    pub fn split_at<const N: usize, Tpl>(tpl: Tpl) -> SplitAt<Tpl, N>
+    where
+        Tpl: TupleSplitAt<N>,
+    {
+        <Tpl as TupleSplitAt<N>>::split_at(tpl)
+    }
+
+    /// A tuple that is usable with [split_at()].
+    ///
+    /// An index larger than the length of the tuple is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use tupleops::split_at;
+    ///
+    /// split_at::<4, _>((1, 2, 3));
+    /// ```
+    ///
+    /// See also: [split_at()], [SplitAt].
+    #[cfg_attr(docsrs, doc(cfg(feature = "split-at")))]
+    pub trait TupleSplitAt<const N: usize> {
+        #[doc(hidden)]
+        type Front;
+
+        #[doc(hidden)]
+        type Back;
+
+        #[doc(hidden)]
+        fn split_at(tpl: Self) -> (Self::Front, Self::Back);
+    }
+
+    impl TupleSplitAt<0> for () {
+        type Front = ();
+        type Back = ();
+
+        #[inline(always)]
+        fn split_at((): ()) -> (Self::Front, Self::Back) {
+            ((), ())
+        }
+    }
+});
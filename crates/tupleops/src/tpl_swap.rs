@@ -0,0 +1,97 @@
+// Not generated with do_impl!(), because a single blanket impl over TupleReplaceAt suffices.
+use crate::TupleReplaceAt;
+
+/// The resulting type when the I-th and the J-th element of a tuple are swapped.
+///
+/// ```
+/// use same_types::assert_same_types;
+/// use tupleops::Swap;
+///
+/// assert_same_types!(
+///     Swap<(u8, u16, u32), 0, 2>,
+///     (u32, u16, u8),
+/// );
+///
+/// assert_same_types!(
+///     Swap<(u8, u16, u32), 1, 1>,
+///     (u8, u16, u32),
+/// );
+/// ```
+///
+/// See also: [swap()], [TupleSwap].
+#[cfg_attr(docsrs, doc(cfg(feature = "swap")))]
+pub type Swap<Tpl, const I: usize, const J: usize> = <Tpl as TupleSwap<I, J>>::Type;
+
+/// Swap the I-th and the J-th element of a tuple.
+///
+/// ```
+/// use tupleops::swap;
+///
+/// assert_eq!(
+///     swap::<0, 2, _>((1, "two", 3.0)),
+///     (3.0, "two", 1),
+/// );
+///
+/// assert_eq!(
+///     swap::<2, 0, _>((1, "two", 3.0)),
+///     (3.0, "two", 1),
+/// );
+///
+/// assert_eq!(
+///     swap::<1, 1, _>((1, "two", 3.0)),
+///     (1, "two", 3.0),
+/// );
+/// ```
+///
+/// See also: [Swap], [TupleSwap].
+#[cfg_attr(docsrs, doc(cfg(feature = "swap")))]
+#[inline(always)]
+pub fn swap<const I: usize, const J: usize, Tpl>(tpl: Tpl) -> Swap<Tpl, I, J>
+where
+    Tpl: TupleSwap<I, J>,
+{
+    <Tpl as TupleSwap<I, J>>::swap(tpl)
+}
+
+/// A tuple that is usable with [swap()].
+///
+/// An index that is out of bounds is a compile time error:
+///
+/// ```compile_fail
+/// use tupleops::swap;
+///
+/// swap::<0, 3, _>((1, 2, 3));
+/// ```
+///
+/// See also: [swap()], [Swap].
+#[cfg_attr(docsrs, doc(cfg(feature = "swap")))]
+pub trait TupleSwap<const I: usize, const J: usize> {
+    #[doc(hidden)]
+    type Type;
+
+    #[doc(hidden)]
+    fn swap(tpl: Self) -> Self::Type;
+}
+
+type Taken<Tpl, const I: usize> = <Tpl as TupleReplaceAt<(), I>>::Type;
+type Elem<Tpl, const I: usize> = <Tpl as TupleReplaceAt<(), I>>::Old;
+type Put<Tpl, New, const I: usize> = <Tpl as TupleReplaceAt<New, I>>::Type;
+
+impl<Tpl, const I: usize, const J: usize> TupleSwap<I, J> for Tpl
+where
+    Tpl: TupleReplaceAt<(), I>,
+    Taken<Tpl, I>: TupleReplaceAt<(), J>,
+    Taken<Taken<Tpl, I>, J>: TupleReplaceAt<Elem<Taken<Tpl, I>, J>, I>,
+    Put<Taken<Taken<Tpl, I>, J>, Elem<Taken<Tpl, I>, J>, I>: TupleReplaceAt<Elem<Tpl, I>, J>,
+{
+    type Type = Put<Put<Taken<Taken<Tpl, I>, J>, Elem<Taken<Tpl, I>, J>, I>, Elem<Tpl, I>, J>;
+
+    #[inline(always)]
+    fn swap(tpl: Self) -> Self::Type {
+        let (tpl, first) = TupleReplaceAt::<(), I>::replace_at(tpl, ());
+        let (tpl, second) = TupleReplaceAt::<(), J>::replace_at(tpl, ());
+        let (tpl, _) = TupleReplaceAt::<_, I>::replace_at(tpl, second);
+        let (tpl, _) = TupleReplaceAt::<_, J>::replace_at(tpl, first);
+        tpl
+    }
+}
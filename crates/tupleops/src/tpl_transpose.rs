@@ -0,0 +1,96 @@
+crate::do_impl!(tuple_transpose, {
+    use crate::{prepend, Prepend, TuplePrepend};
+
+    /// The resulting type when a non-empty tuple of tuples of the same length is transposed.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::Transpose;
+    ///
+    /// assert_same_types!(
+    ///     Transpose<((u8, u16), (i8, i16), (f32, f64))>,
+    ///     ((u8, i8, f32), (u16, i16, f64)),
+    /// );
+    /// ```
+    ///
+    /// See also: [transpose_tuple()], [TupleTranspose].
+    #[cfg_attr(docsrs, doc(cfg(feature = "transpose")))]
+    pub type Transpose<Tpl> = <Tpl as TupleTranspose<Tpl>>::Type;
+
+    /// Transpose a non-empty tuple of tuples of the same length.
+    ///
+    /// The N-th tuple of the result contains the N-th element of every input tuple.
+    ///
+    /// ```
+    /// use tupleops::transpose_tuple;
+    ///
+    /// assert_eq!(
+    ///     transpose_tuple(((1, 2), ("a", "b"), (1.0, 2.0))),
+    ///     ((1, "a", 1.0), (2, "b", 2.0)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     transpose_tuple(((1, 2, 3),)),
+    ///     ((1,), (2,), (3,)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     transpose_tuple(((), (), ())),
+    ///     (),
+    /// );
+    /// ```
+    ///
+    /// See also: [Transpose], [TupleTranspose].
+    #[cfg_attr(docsrs, doc(cfg(feature = "transpose")))]
+    #[inline(always)]
+    pub fn transpose_tuple<Tpl>(tpl: Tpl) -> Transpose<Tpl>
+    where
+        Tpl: TupleTranspose<Tpl>,
+    {
+        <Tpl as TupleTranspose<Tpl>>::transpose_tuple(tpl)
+    }
+
+    /// A tuple of tuples that is usable with [transpose_tuple()].
+    ///
+    /// Tuples of different lengths cannot be transposed:
+    ///
+    /// ```compile_fail
+    /// use tupleops::transpose_tuple;
+    ///
+    /// transpose_tuple(((1, 2), (3,)));
+    /// ```
+    ///
+    /// See also: [transpose_tuple()], [Transpose].
+    #[cfg_attr(docsrs, doc(cfg(feature = "transpose")))]
+    pub trait TupleTranspose<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn transpose_tuple(tpl: Tpl) -> Self::Type;
+    }
+
+    /// Element-wise prepend the elements of a tuple to the tuples of a tuple of tuples.
+    #[doc(hidden)]
+    pub trait TupleZipPrepend<Heads, Tails> {
+        type Type;
+
+        fn zip_prepend(heads: Heads, tails: Tails) -> Self::Type;
+    }
+
+    impl TupleTranspose<((),)> for ((),) {
+        type Type = ();
+
+        #[inline(always)]
+        fn transpose_tuple(tpl: ((),)) -> Self::Type {
+            let ((),) = tpl;
+        }
+    }
+
+    impl TupleZipPrepend<(), ()> for ((), ()) {
+        type Type = ();
+
+        #[inline(always)]
+        fn zip_prepend((): (), (): ()) -> Self::Type {}
+    }
+});
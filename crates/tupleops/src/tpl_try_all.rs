@@ -0,0 +1,114 @@
+crate::do_impl!(tuple_try_all, {
+    /// The type when all closures of a tuple of closures returned [Ok].
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::TryAll;
+    ///
+    /// assert_same_types!(
+    ///     TryAll<(fn() -> Result<u8, ()>, fn() -> Result<u16, ()>)>,
+    ///     (u8, u16),
+    /// );
+    /// ```
+    ///
+    /// See also: [try_all()], [TupleTryAll].
+    #[cfg_attr(docsrs, doc(cfg(feature = "try-all")))]
+    pub type TryAll<Tpl> = <Tpl as TupleTryAll<Tpl>>::Type;
+
+    /// Call a non-empty tuple of closures that return [Result]s from first to last, and unwrap
+    /// the results if all closures returned [Ok].
+    /// Return the first error otherwise, without calling the remaining closures.
+    ///
+    /// The error type is the error type of the first closure. Like the `?` operator, the errors
+    /// of the other closures are converted into it with [From].
+    ///
+    /// ```
+    /// use tupleops::try_all;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Parse,
+    ///     Utf8,
+    /// }
+    ///
+    /// impl From<core::num::ParseIntError> for Error {
+    ///     fn from(_: core::num::ParseIntError) -> Self {
+    ///         Error::Parse
+    ///     }
+    /// }
+    ///
+    /// impl From<core::str::Utf8Error> for Error {
+    ///     fn from(_: core::str::Utf8Error) -> Self {
+    ///         Error::Utf8
+    ///     }
+    /// }
+    ///
+    /// let result = try_all((
+    ///     || Ok::<_, Error>(1.0),
+    ///     || "2".parse::<u8>(),
+    ///     || core::str::from_utf8(b"three"),
+    /// ));
+    /// assert_eq!(result, Ok((1.0, 2, "three")));
+    ///
+    /// let mut calls = 0;
+    /// let result = try_all((
+    ///     || "x".parse::<u8>(),
+    ///     || {
+    ///         calls += 1;
+    ///         "2".parse::<u8>()
+    ///     },
+    /// ));
+    /// assert!(result.is_err());
+    /// assert_eq!(calls, 0);
+    /// ```
+    ///
+    /// The error type is inferred, so the result can be used with the `?` operator directly:
+    ///
+    /// ```
+    /// use tupleops::try_all;
+    ///
+    /// fn parse(a: &str, b: &str) -> Result<(u8, u16), Box<dyn std::error::Error>> {
+    ///     let result = try_all((|| a.parse::<u8>(), || b.parse::<u16>()))?;
+    ///     Ok(result)
+    /// }
+    ///
+    /// assert_eq!(parse("1", "2").unwrap(), (1, 2));
+    /// assert!(parse("1", "x").is_err());
+    /// ```
+    ///
+    /// See also: [TryAll], [TupleTryAll].
+    #[cfg_attr(docsrs, doc(cfg(feature = "try-all")))]
+    #[inline(always)]
+    pub fn try_all<Tpl>(tpl: Tpl) -> Result<TryAll<Tpl>, <Tpl as TupleTryAll<Tpl>>::Error>
+    where
+        Tpl: TupleTryAll<Tpl>,
+    {
+        <Tpl as TupleTryAll<Tpl>>::try_all(tpl)
+    }
+
+    /// A non-empty tuple of closures that is usable with [try_all()].
+    ///
+    /// Errors that cannot be converted into the error type of the first closure are rejected:
+    ///
+    /// ```compile_fail
+    /// use tupleops::try_all;
+    ///
+    /// let result = try_all((
+    ///     || "1".parse::<u8>(),
+    ///     || core::str::from_utf8(b"two"),
+    /// ));
+    /// ```
+    ///
+    /// See also: [try_all()], [TryAll].
+    #[cfg_attr(docsrs, doc(cfg(feature = "try-all")))]
+    pub trait TupleTryAll<Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        type Error;
+
+        #[doc(hidden)]
+        fn try_all(tpl: Tpl) -> Result<Self::Type, Self::Error>;
+    }
+});
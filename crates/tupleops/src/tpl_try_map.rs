@@ -0,0 +1,136 @@
+crate::do_impl!(tuple_try_map, {
+    /// The type of a tuple when element-wise mapped with a fallible mapper, if no error occurred.
+    ///
+    /// See also: [try_map_tuple()], [TupleTryMapper], [TupleTryMap].
+    #[cfg_attr(docsrs, doc(cfg(feature = "try-map")))]
+    pub type TryMapTuple<Mapper, Tpl> = <(Mapper, Tpl) as TupleTryMap<Mapper, Tpl>>::Type;
+
+    /// Element-wise map a tuple with a fallible mapper.
+    ///
+    /// The elements are mapped from first to last.
+    /// The function returns the first error, without mapping the remaining elements.
+    ///
+    /// ```
+    /// use tupleops::{try_map_tuple, TupleTryMapper};
+    ///
+    /// struct Budget {
+    ///     left: usize,
+    ///     calls: usize,
+    /// }
+    ///
+    /// impl TupleTryMapper for &mut Budget {
+    ///     type MapElem<Elem> = Elem;
+    ///     type Error = &'static str;
+    ///
+    ///     fn try_map_elem<Elem>(&mut self, elem: Elem) -> Result<Elem, Self::Error> {
+    ///         self.calls += 1;
+    ///         if self.left == 0 {
+    ///             return Err("budget exceeded");
+    ///         }
+    ///         self.left -= 1;
+    ///         Ok(elem)
+    ///     }
+    /// }
+    ///
+    /// let mut budget = Budget { left: 3, calls: 0 };
+    /// assert_eq!(
+    ///     try_map_tuple(&mut budget, (1, "two", 3.0)),
+    ///     Ok((1, "two", 3.0)),
+    /// );
+    /// assert_eq!(budget.calls, 3);
+    ///
+    /// let mut budget = Budget { left: 1, calls: 0 };
+    /// assert_eq!(
+    ///     try_map_tuple(&mut budget, (1, "two", 3.0)),
+    ///     Err("budget exceeded"),
+    /// );
+    /// assert_eq!(budget.calls, 2);
+    /// ```
+    ///
+    /// See also: [TryMapTuple], [TupleTryMapper], [TupleTryMap].
+    #[cfg_attr(docsrs, doc(cfg(feature = "try-map")))]
+    #[inline(always)]
+    pub fn try_map_tuple<Mapper: TupleTryMapper, Tpl>(
+        mapper: Mapper,
+        tpl: Tpl,
+    ) -> Result<TryMapTuple<Mapper, Tpl>, <Mapper as TupleTryMapper>::Error>
+    where
+        (Mapper, Tpl): TupleTryMap<Mapper, Tpl>,
+    {
+        <(Mapper, Tpl) as TupleTryMap<Mapper, Tpl>>::try_map_tuple(mapper, tpl)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "try-map")))]
+    /// Helper trait to element-wise map a tuple with a fallible mapper.
+    ///
+    /// See also: [try_map_tuple()], [TryMapTuple], [TupleTryMap].
+    pub trait TupleTryMapper {
+        /// The result type after successfully mapping Elem.
+        type MapElem<Elem>;
+
+        /// The error type if mapping an element failed.
+        type Error;
+
+        /// Map an element, or return an error.
+        fn try_map_elem<Elem>(&mut self, elem: Elem) -> Result<Self::MapElem<Elem>, Self::Error>;
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "try-map")))]
+    /// A [TupleTryMapper] and a tuple that are usable with [try_map_tuple()].
+    ///
+    /// If an error occurs, then the already mapped elements and the remaining input elements
+    /// are dropped:
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use tupleops::{try_map_tuple, TupleTryMapper};
+    ///
+    /// struct FailAt(usize);
+    ///
+    /// impl TupleTryMapper for FailAt {
+    ///     type MapElem<Elem> = (Elem,);
+    ///     type Error = ();
+    ///
+    ///     fn try_map_elem<Elem>(&mut self, elem: Elem) -> Result<(Elem,), ()> {
+    ///         match self.0 {
+    ///             0 => Err(()),
+    ///             _ => {
+    ///                 self.0 -= 1;
+    ///                 Ok((elem,))
+    ///             },
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let rc = Rc::new(());
+    /// let tpl = (rc.clone(), rc.clone(), rc.clone(), rc.clone());
+    /// assert_eq!(Rc::strong_count(&rc), 5);
+    ///
+    /// assert!(try_map_tuple(FailAt(2), tpl).is_err());
+    /// assert_eq!(Rc::strong_count(&rc), 1);
+    /// ```
+    ///
+    /// See also: [try_map_tuple()], [TryMapTuple], [TupleTryMapper].
+    pub trait TupleTryMap<Mapper: TupleTryMapper, Tpl> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn try_map_tuple(
+            mapper: Mapper,
+            tpl: Tpl,
+        ) -> Result<Self::Type, <Mapper as TupleTryMapper>::Error>;
+    }
+
+    impl<Mapper: TupleTryMapper> TupleTryMap<Mapper, ()> for (Mapper, ()) {
+        type Type = ();
+
+        fn try_map_tuple(
+            _mapper: Mapper,
+            _tpl: (),
+        ) -> Result<Self::Type, <Mapper as TupleTryMapper>::Error> {
+            Ok(())
+        }
+    }
+});
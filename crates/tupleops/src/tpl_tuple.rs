@@ -1,4 +1,4 @@
-crate::do_impl!("tuple", tuple_tuple, {
+crate::do_impl!(tuple_tuple, {
     /// A tuple.
     #[cfg_attr(docsrs, doc(cfg(feature = "tuple")))]
     pub trait Tuple {}
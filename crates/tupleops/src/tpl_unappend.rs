@@ -1,4 +1,4 @@
-crate::do_impl!("unappend", tuple_unappend, {
+crate::do_impl!(tuple_unappend, {
     /// The resulting tuple when the last element is removed from a tuple.
     ///
     /// ```
@@ -1,4 +1,4 @@
-crate::do_impl!("unprepend", tuple_unprepend, {
+crate::do_impl!(tuple_unprepend, {
     /// The type of the first element of the tuple.
     ///
     /// ```
@@ -0,0 +1,81 @@
+crate::do_impl!(tuple_unzip, {
+    /// The resulting tuple of two tuples when a tuple of pairs is unzipped.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::UnzipTuple;
+    ///
+    /// assert_same_types!(
+    ///     UnzipTuple<((u8, i8), (u16, i16))>,
+    ///     ((u8, u16), (i8, i16)),
+    /// );
+    /// ```
+    ///
+    /// See also: [unzip_tuple()], [TupleUnzip].
+    #[cfg_attr(docsrs, doc(cfg(feature = "unzip")))]
+    pub type UnzipTuple<Tpl> = (
+        <Tpl as TupleUnzip<Tpl>>::Left,
+        <Tpl as TupleUnzip<Tpl>>::Right,
+    );
+
+    /// Unzip a tuple of pairs into a pair of tuples.
+    ///
+    /// This is the inverse operation of `zip_tuples()`.
+    ///
+    /// ```
+    /// use tupleops::unzip_tuple;
+    ///
+    /// assert_eq!(
+    ///     unzip_tuple(((1, "one"), (2, "two"), (3, "three"))),
+    ///     ((1, 2, 3), ("one", "two", "three")),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     unzip_tuple(()),
+    ///     ((), ()),
+    /// );
+    /// ```
+    ///
+    /// See also: [UnzipTuple], [TupleUnzip].
+    #[cfg_attr(docsrs, doc(cfg(feature = "unzip")))]
+    #[inline(always)]
+    pub fn unzip_tuple<Tpl>(tpl: Tpl) -> UnzipTuple<Tpl>
+    where
+        Tpl: TupleUnzip<Tpl>,
+    {
+        <Tpl as TupleUnzip<Tpl>>::unzip_tuple(tpl)
+    }
+
+    /// A tuple of pairs that is usable with [unzip_tuple()].
+    ///
+    /// Only tuples whose elements are all pairs can be unzipped:
+    ///
+    /// ```compile_fail
+    /// use tupleops::unzip_tuple;
+    ///
+    /// unzip_tuple(((1, "one"), 2));
+    /// ```
+    ///
+    /// See also: [unzip_tuple()], [UnzipTuple].
+    #[cfg_attr(docsrs, doc(cfg(feature = "unzip")))]
+    pub trait TupleUnzip<Tpl> {
+        #[doc(hidden)]
+        type Left;
+
+        #[doc(hidden)]
+        type Right;
+
+        #[doc(hidden)]
+        fn unzip_tuple(tpl: Tpl) -> (Self::Left, Self::Right);
+    }
+
+    impl TupleUnzip<()> for () {
+        type Left = ();
+        type Right = ();
+
+        #[inline(always)]
+        fn unzip_tuple((): ()) -> (Self::Left, Self::Right) {
+            ((), ())
+        }
+    }
+});
@@ -0,0 +1,86 @@
+crate::do_impl!(tuple_zip, {
+    /// The resulting type when two tuples of the same length are zipped element-wise.
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::ZipTuples;
+    ///
+    /// assert_same_types!(
+    ///     ZipTuples<(u8, u16), (i8, i16)>,
+    ///     ((u8, i8), (u16, i16)),
+    /// );
+    /// ```
+    ///
+    /// See also: [zip_tuples()], [TupleZip].
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip")))]
+    pub type ZipTuples<Left, Right> = <(Left, Right) as TupleZip<Left, Right>>::Type;
+
+    /// Zip two tuples of the same length into a tuple of pairs.
+    ///
+    /// ```
+    /// use tupleops::zip_tuples;
+    ///
+    /// assert_eq!(
+    ///     zip_tuples((1, 2, 3), ("one", "two", "three")),
+    ///     ((1, "one"), (2, "two"), (3, "three")),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     zip_tuples((), ()),
+    ///     (),
+    /// );
+    /// ```
+    ///
+    /// See also: [ZipTuples], [TupleZip].
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip")))]
+    #[inline(always)]
+    pub fn zip_tuples<Left, Right>(left: Left, right: Right) -> ZipTuples<Left, Right>
+    where
+        (Left, Right): TupleZip<Left, Right>,
+    {
+        <(Left, Right) as TupleZip<Left, Right>>::zip_tuples(left, right)
+    }
+
+    /// Two tuples of the same length that are usable with [zip_tuples()].
+    ///
+    /// Tuples of different lengths cannot be zipped:
+    ///
+    /// ```compile_fail
+    /// use tupleops::zip_tuples;
+    ///
+    /// zip_tuples((1, 2, 3), ("one", "two"));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use tupleops::zip_tuples;
+    ///
+    /// zip_tuples((1, 2), (3,));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use tupleops::ZipTuples;
+    ///
+    /// let _: ZipTuples<(u8, u16), (u32,)>;
+    /// ```
+    ///
+    /// A direct call of [zip_tuples()] fails with a mismatched types error for the second tuple.
+    /// If both types are already known, e.g. in [ZipTuples] or in generic code, the trait is not
+    /// implemented.
+    ///
+    /// See also: [zip_tuples()], [ZipTuples].
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip")))]
+    pub trait TupleZip<Left, Right> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn zip_tuples(left: Left, right: Right) -> Self::Type;
+    }
+
+    impl TupleZip<(), ()> for ((), ()) {
+        type Type = ();
+
+        #[inline(always)]
+        fn zip_tuples((): (), (): ()) -> Self::Type {}
+    }
+});
@@ -0,0 +1,119 @@
+crate::do_impl!(tuple_zip_with, {
+    /// The resulting type when two tuples of the same length are combined element-wise with a
+    /// [TupleBiMapper].
+    ///
+    /// ```
+    /// use same_types::assert_same_types;
+    /// use tupleops::{TupleBiMapper, ZipWith};
+    ///
+    /// struct Pair;
+    ///
+    /// impl<A, B> TupleBiMapper<A, B> for Pair {
+    ///     type MapElem = (A, B);
+    ///
+    ///     fn map_elems(&mut self, a: A, b: B) -> Self::MapElem {
+    ///         (a, b)
+    ///     }
+    /// }
+    ///
+    /// assert_same_types!(
+    ///     ZipWith<Pair, (u8, u16), (i8, i16)>,
+    ///     ((u8, i8), (u16, i16)),
+    /// );
+    /// ```
+    ///
+    /// See also: [zip_with()], [TupleBiMapper], [TupleZipWith].
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip-with")))]
+    pub type ZipWith<Mapper, Left, Right> =
+        <(Mapper, Left, Right) as TupleZipWith<Mapper, Left, Right>>::Type;
+
+    /// Combine two tuples of the same length element-wise with a mapper.
+    ///
+    /// The elements are combined from first to last.
+    ///
+    /// ```
+    /// use tupleops::{zip_with, TupleBiMapper};
+    ///
+    /// struct Override;
+    ///
+    /// impl<T> TupleBiMapper<T, Option<T>> for Override {
+    ///     type MapElem = T;
+    ///
+    ///     fn map_elems(&mut self, default: T, value: Option<T>) -> T {
+    ///         value.unwrap_or(default)
+    ///     }
+    /// }
+    ///
+    /// let defaults = (8080_u16, "localhost", false);
+    /// let overrides = (None, Some("example.com"), Some(true));
+    /// assert_eq!(
+    ///     zip_with(Override, defaults, overrides),
+    ///     (8080, "example.com", true),
+    /// );
+    /// ```
+    ///
+    /// See also: [ZipWith], [TupleBiMapper], [TupleZipWith].
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip-with")))]
+    #[inline(always)]
+    pub fn zip_with<Mapper, Left, Right>(
+        mapper: Mapper,
+        left: Left,
+        right: Right,
+    ) -> ZipWith<Mapper, Left, Right>
+    where
+        (Mapper, Left, Right): TupleZipWith<Mapper, Left, Right>,
+    {
+        <(Mapper, Left, Right) as TupleZipWith<Mapper, Left, Right>>::zip_with(mapper, left, right)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip-with")))]
+    /// Helper trait to combine the elements A and B at the same position of two tuples.
+    ///
+    /// Implement it for every pair of element types that the mapper should accept.
+    ///
+    /// See also: [zip_with()], [ZipWith], [TupleZipWith].
+    pub trait TupleBiMapper<A, B> {
+        /// The result type after combining A and B.
+        type MapElem;
+
+        /// Combine two elements.
+        fn map_elems(&mut self, a: A, b: B) -> Self::MapElem;
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip-with")))]
+    /// A mapper and two tuples of the same length that are usable with [zip_with()].
+    ///
+    /// Tuples of different lengths cannot be combined:
+    ///
+    /// ```compile_fail
+    /// use tupleops::{zip_with, TupleBiMapper};
+    ///
+    /// struct Pair;
+    ///
+    /// impl<A, B> TupleBiMapper<A, B> for Pair {
+    ///     type MapElem = (A, B);
+    ///
+    ///     fn map_elems(&mut self, a: A, b: B) -> Self::MapElem {
+    ///         (a, b)
+    ///     }
+    /// }
+    ///
+    /// zip_with(Pair, (1, 2, 3), ("one", "two"));
+    /// ```
+    ///
+    /// See also: [zip_with()], [ZipWith], [TupleBiMapper].
+    pub trait TupleZipWith<Mapper, Left, Right> {
+        #[doc(hidden)]
+        type Type;
+
+        #[doc(hidden)]
+        fn zip_with(mapper: Mapper, left: Left, right: Right) -> Self::Type;
+    }
+
+    impl<Mapper> TupleZipWith<Mapper, (), ()> for (Mapper, (), ()) {
+        type Type = ();
+
+        #[inline(always)]
+        fn zip_with(_mapper: Mapper, (): (), (): ()) -> Self::Type {}
+    }
+});